@@ -51,3 +51,17 @@ pub(crate) fn datetime_to_ical_format(dt: &DateTime) -> String {
     let dt = dt.format("%Y%m%dT%H%M%S");
     format!("{}:{}{}", tz_prefix, dt, tz_postfix)
 }
+
+/// Generates an iCalendar date string format with the prefix symbols, for a date-only value.
+/// Like: `;VALUE=DATE:19970714` or `;TZID=America/New_York;VALUE=DATE:19970714`
+/// ref: <https://tools.ietf.org/html/rfc5545#section-3.3.4>
+pub(crate) fn date_to_ical_format(dt: &DateTime) -> String {
+    let tz_prefix = match dt.timezone() {
+        Tz::Local(_) => String::new(),
+        // A date can't have the `Z` suffix, so UTC is set as a parameter
+        Tz::Tz(tz) => format!(";TZID={}", tz.name()),
+    };
+
+    let date = dt.format("%Y%m%d");
+    format!("{};VALUE=DATE:{}", tz_prefix, date)
+}
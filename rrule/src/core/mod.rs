@@ -1,12 +1,18 @@
 mod datetime;
 mod rrule;
 mod rruleset;
+mod rruleset_builder;
+mod snapshot;
+mod text;
 mod timezone;
 mod timezone_impl;
 pub(crate) mod utils;
 
-pub use self::rrule::{Frequency, NWeekday, RRule};
-pub use self::rruleset::{RRuleResult, RRuleSet};
+pub use self::rrule::{Frequency, NWeekday, RRule, Skip};
+pub use self::rruleset::{Event, Explanation, RRuleResult, RRuleSet};
+pub use self::rruleset_builder::RRuleSetBuilder;
+pub use self::snapshot::{RecurrenceSnapshot, RuleSnapshot};
+pub use self::text::{English, Language};
 pub(crate) use datetime::{
     duration_from_midnight, get_day, get_hour, get_minute, get_month, get_second, DateTime,
 };
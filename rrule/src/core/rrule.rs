@@ -4,17 +4,22 @@ use crate::core::get_hour;
 use crate::core::get_minute;
 use crate::core::get_month;
 use crate::core::get_second;
+use crate::iter::rrule_iter::WasLimited;
 use crate::iter::RRuleIter;
 use crate::parser::str_to_weekday;
 use crate::parser::ContentLineCaptures;
 use crate::parser::ParseError;
 use crate::validator::validate_rrule;
 use crate::validator::ValidationError;
-use crate::{RRuleError, RRuleSet, Unvalidated, Validated};
-use chrono::{Datelike, Month, Weekday};
+use crate::{English, Language, RRuleError, RRuleSet, Unvalidated, Validated};
+use chrono::{
+    Datelike, Duration, Month, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+    Weekday,
+};
 #[cfg(feature = "serde")]
 use serde_with::{serde_as, DeserializeFromStr, SerializeDisplay};
 use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::marker::PhantomData;
@@ -40,6 +45,59 @@ pub enum Frequency {
     Secondly = 6,
 }
 
+impl Frequency {
+    /// Returns `true` if the frequency is shorter than a day,
+    /// so [`Frequency::Hourly`], [`Frequency::Minutely`] or [`Frequency::Secondly`].
+    #[must_use]
+    pub fn is_sub_daily(&self) -> bool {
+        matches!(self, Self::Hourly | Self::Minutely | Self::Secondly)
+    }
+}
+
+/// How invalid dates, like the 31st of a month with 30 days, are handled.
+///
+/// Corresponds to the `SKIP` rule part of [RFC 7529](https://datatracker.ietf.org/doc/html/rfc7529#section-4.1).
+/// Invalid dates are only generated by monthly and yearly rules with a `BYMONTHDAY`,
+/// which can be filled in from the start date.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(DeserializeFromStr, SerializeDisplay))]
+pub enum Skip {
+    /// Invalid dates are skipped, like defined in RFC 5545.
+    #[default]
+    Omit,
+    /// Invalid dates are moved to the last valid date before them,
+    /// for example from February 30th to February 28th.
+    Backward,
+    /// Invalid dates are moved to the first valid date after them,
+    /// for example from February 30th to March 1st.
+    Forward,
+}
+
+impl Display for Skip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Omit => "OMIT",
+            Self::Backward => "BACKWARD",
+            Self::Forward => "FORWARD",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Skip {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let skip = match &value.to_uppercase()[..] {
+            "OMIT" => Self::Omit,
+            "BACKWARD" => Self::Backward,
+            "FORWARD" => Self::Forward,
+            val => return Err(ParseError::InvalidSkip(val.to_string())),
+        };
+        Ok(skip)
+    }
+}
+
 impl Display for Frequency {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let name = match self {
@@ -157,10 +215,13 @@ impl FromStr for NWeekday {
             return Err(ParseError::InvalidWeekday(value.into()));
         }
 
-        // it doesn't have any issue, because we checked the string is ASCII above
-        let wd = str_to_weekday(&value[(length - 2)..])
-            .map_err(|_| ParseError::InvalidWeekday(value.into()))?;
-        let nth = value[..(length - 2)].parse::<i16>().unwrap_or_default();
+        // Split on the last 2 bytes, this fails when they are not on a char boundary.
+        let (nth, wd) = match (value.get(..(length - 2)), value.get((length - 2)..)) {
+            (Some(nth), Some(wd)) => (nth, wd),
+            _ => return Err(ParseError::InvalidWeekday(value.into())),
+        };
+        let wd = str_to_weekday(wd).map_err(|_| ParseError::InvalidWeekday(value.into()))?;
+        let nth = nth.parse::<i16>().unwrap_or_default();
 
         if nth == 0 {
             Ok(Self::Every(wd))
@@ -273,6 +334,8 @@ pub struct RRule<Stage = Validated> {
     /// Can be a value from -366 to 366.
     /// Note: Only used when `by-easter` feature flag is set. Otherwise, it is ignored.
     pub(crate) by_easter: Option<i16>,
+    /// How invalid dates are handled.
+    pub(crate) skip: Skip,
     /// A phantom data to have the stage (unvalidated or validated).
     #[cfg_attr(feature = "serde", serde_as(as = "ignore"))]
     pub(crate) stage: PhantomData<Stage>,
@@ -298,6 +361,7 @@ impl Default for RRule<Unvalidated> {
             by_minute: Vec::new(),
             by_second: Vec::new(),
             by_easter: None,
+            skip: Skip::Omit,
             stage: PhantomData,
         }
     }
@@ -441,6 +505,14 @@ impl RRule<Unvalidated> {
         self
     }
 
+    /// Sets how invalid dates are handled, like the 31st of a month with 30 days.
+    /// Defaults to [`Skip::Omit`].
+    #[must_use]
+    pub fn skip(mut self, skip: Skip) -> Self {
+        self.skip = skip;
+        self
+    }
+
     /// Fills in some additional fields in order to make iter work correctly.
     pub(crate) fn finalize_parsed_rrule(mut self, dt_start: &DateTime) -> Self {
         // TEMP: move negative months to another list
@@ -492,7 +564,7 @@ impl RRule<Unvalidated> {
         }
 
         // by_hour
-        if self.by_hour.is_empty() && self.freq < Frequency::Hourly {
+        if self.by_hour.is_empty() && !self.freq.is_sub_daily() {
             let hour = get_hour(dt_start);
             self.by_hour = vec![hour];
         }
@@ -549,6 +621,7 @@ impl RRule<Unvalidated> {
     ///
     /// If the properties aren't valid, it will return [`RRuleError`].
     pub fn validate(self, dt_start: DateTime) -> Result<RRule<Validated>, RRuleError> {
+        validate_rrule::validate_by_list_sizes(&self)?;
         let rrule = self.finalize_parsed_rrule(&dt_start);
 
         // Validate required checks (defined by RFC 5545)
@@ -594,10 +667,22 @@ impl RRule<Unvalidated> {
             by_minute: rrule.by_minute,
             by_second: rrule.by_second,
             by_easter: rrule.by_easter,
+            skip: rrule.skip,
             stage: PhantomData,
         })
     }
 
+    /// Checks if the [`RRule`] is valid with the given `dt_start`, without consuming it.
+    ///
+    /// This runs the same checks as [`RRule::validate`].
+    ///
+    /// # Errors
+    ///
+    /// If the properties aren't valid, it will return [`RRuleError`].
+    pub fn check(&self, dt_start: DateTime) -> Result<(), RRuleError> {
+        self.clone().validate(dt_start).map(|_| ())
+    }
+
     /// Validates the [`RRule`] with the given `dt_start` and creates an [`RRuleSet`] struct.
     ///
     /// # Errors
@@ -611,11 +696,398 @@ impl RRule<Unvalidated> {
 }
 
 impl RRule {
+    /// Returns the weekdays on which the rrule can have recurrences, without expanding it.
+    ///
+    /// These are the weekdays of `BYDAY`. Validation fills `BYDAY` with the weekday of the
+    /// start datetime for weekly rules without other BYxxx rule parts. If there is no `BYDAY`,
+    /// for example for a daily rule, recurrences can be on any weekday.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Weekday};
+    /// use rrule::{Frequency, NWeekday, RRule, Tz};
+    ///
+    /// let rrule = RRule::new(Frequency::Monthly)
+    ///     .by_weekday(vec![NWeekday::Every(Weekday::Mon), NWeekday::Nth(1, Weekday::Fri)])
+    ///     .validate(Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rrule.active_weekdays(), [Weekday::Mon, Weekday::Fri].into());
+    /// ```
+    #[must_use]
+    pub fn active_weekdays(&self) -> HashSet<Weekday> {
+        if self.by_weekday.is_empty() {
+            return [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ]
+            .into();
+        }
+        self.by_weekday
+            .iter()
+            .map(|weekday| match weekday {
+                NWeekday::Every(weekday) | NWeekday::Nth(_, weekday) => *weekday,
+            })
+            .collect()
+    }
+
+    /// Returns the ordinals of the `BYDAY` weekdays, grouped by weekday.
+    ///
+    /// For example, `BYDAY=1MO,3MO,-1FR` maps Monday to `[1, 3]` and Friday to `[-1]`.
+    /// A weekday without an ordinal, like `MO`, maps to an empty list.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Weekday};
+    /// use rrule::{Frequency, NWeekday, RRule, Tz};
+    ///
+    /// let rrule = RRule::new(Frequency::Monthly)
+    ///     .by_weekday(vec![NWeekday::Nth(1, Weekday::Mon), NWeekday::Nth(3, Weekday::Mon)])
+    ///     .validate(Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rrule.nth_weekday_map()[&Weekday::Mon], vec![1, 3]);
+    /// ```
+    #[must_use]
+    pub fn nth_weekday_map(&self) -> HashMap<Weekday, Vec<i32>> {
+        let mut map: HashMap<Weekday, Vec<i32>> = HashMap::new();
+        for weekday in &self.by_weekday {
+            match weekday {
+                NWeekday::Every(weekday) => {
+                    map.entry(*weekday).or_default();
+                }
+                NWeekday::Nth(n, weekday) => map.entry(*weekday).or_default().push(i32::from(*n)),
+            }
+        }
+        map
+    }
+
+    /// Returns the number of recurrences of the rrule between `after` and `before`, both
+    /// inclusive.
+    ///
+    /// Only the rrule itself is expanded, so exclusions like `EXDATE`, which are part
+    /// of an [`RRuleSet`], aren't taken into account.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{Frequency, RRule, Tz};
+    ///
+    /// let dt_start = Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap();
+    /// let rrule = RRule::new(Frequency::Daily).validate(dt_start).unwrap();
+    ///
+    /// let after = Tz::UTC.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap();
+    /// let before = Tz::UTC.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(rrule.occurrence_count_between(dt_start, after, before), 28);
+    /// ```
+    #[must_use]
+    pub fn occurrence_count_between(
+        &self,
+        dt_start: DateTime,
+        after: DateTime,
+        before: DateTime,
+    ) -> usize {
+        let mut iter = self.iter_with_ctx(dt_start, true);
+        iter.skip_before(&after);
+        iter.skip_while(|dt| *dt < after)
+            .take_while(|dt| *dt <= before)
+            .count()
+    }
+
+    /// Returns `true` if `dt` is a recurrence of the rrule starting at `dt_start`.
+    ///
+    /// Only the rrule itself is checked, so exclusions like `EXDATE`, which are part
+    /// of an [`RRuleSet`], aren't taken into account. The month, day, weekday and time of
+    /// `dt` are checked against the BYxxx rule parts first. If they match, only the period
+    /// of the frequency which contains `dt` is expanded, if it is a multiple of the interval
+    /// away from the period of `dt_start`. Rules with a `COUNT` are still expanded from
+    /// `dt_start`, as every recurrence is needed to know when the count is reached.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{Frequency, RRule, Tz};
+    ///
+    /// let dt_start = Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap();
+    /// let rrule = RRule::new(Frequency::Weekly).interval(2).validate(dt_start).unwrap();
+    ///
+    /// let dt = Tz::UTC.with_ymd_and_hms(2021, 1, 15, 9, 0, 0).unwrap();
+    /// assert_eq!(rrule.matches(dt_start, dt), Ok(true));
+    /// let dt = Tz::UTC.with_ymd_and_hms(2021, 1, 8, 9, 0, 0).unwrap();
+    /// assert_eq!(rrule.matches(dt_start, dt), Ok(false));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::IterError`] if the validation limits were reached
+    /// before `dt`.
+    pub fn matches(&self, dt_start: DateTime, dt: DateTime) -> Result<bool, RRuleError> {
+        if dt < dt_start || !self.matches_rule_parts(&dt.with_timezone(&dt_start.timezone())) {
+            return Ok(false);
+        }
+
+        let iter = self.iter_with_ctx(dt_start, true);
+        let iters = if iter.can_skip() || self.count.is_some() {
+            vec![iter]
+        } else {
+            self.period_starts(dt_start, dt)
+                .into_iter()
+                .map(|start| {
+                    let mut iter = self.iter_with_ctx(start, true);
+                    // The period can also generate dates before its start,
+                    // like a day moved by `SKIP`
+                    iter.dt_start = dt_start;
+                    iter
+                })
+                .collect()
+        };
+        for mut iter in iters {
+            iter.skip_before(&dt);
+            if iter
+                .by_ref()
+                .take_while(|date| *date <= dt)
+                .any(|date| date == dt)
+            {
+                return Ok(true);
+            }
+            if iter.was_limited() {
+                return Err(RRuleError::new_iter_err(
+                    "Reached the validation limits before the datetime.",
+                ));
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns the start of each period of the frequency which can generate `dt`,
+    /// and is a multiple of the interval away from the period of `dt_start`.
+    ///
+    /// The period of `dt_start` itself starts at `dt_start`. Usually only the period containing
+    /// `dt` can generate it, but `SKIP` can move a day into the next or previous month, and a
+    /// week of `BYWEEKNO` can start or end in the next or previous year.
+    fn period_starts(&self, dt_start: DateTime, dt: DateTime) -> Vec<DateTime> {
+        let tz = dt_start.timezone();
+        let start = dt_start.naive_local();
+        let target = dt.with_timezone(&tz).naive_local();
+        let week_start = |date: NaiveDate| {
+            let days = (7 + date.weekday().num_days_from_monday()
+                - self.week_start.num_days_from_monday())
+                % 7;
+            date - Duration::days(i64::from(days))
+        };
+        let truncate = |datetime: NaiveDateTime, seconds: i64| {
+            let from_midnight = i64::from(datetime.num_seconds_from_midnight());
+            datetime - Duration::seconds(from_midnight % seconds)
+        };
+
+        // The period of `dt`, counted from the period of `dt_start`
+        let period = match self.freq {
+            Frequency::Yearly => i64::from(target.year() - start.year()),
+            Frequency::Monthly => {
+                i64::from(target.year() - start.year()) * 12 + i64::from(target.month())
+                    - i64::from(start.month())
+            }
+            Frequency::Weekly => {
+                (week_start(target.date()) - week_start(start.date())).num_days() / 7
+            }
+            Frequency::Daily => (target.date() - start.date()).num_days(),
+            Frequency::Hourly => (truncate(target, 3600) - truncate(start, 3600)).num_hours(),
+            Frequency::Minutely => (truncate(target, 60) - truncate(start, 60)).num_minutes(),
+            Frequency::Secondly => (target - start).num_seconds(),
+        };
+        let periods = match self.freq {
+            Frequency::Yearly if !self.by_week_no.is_empty() => period - 1..=period + 1,
+            Frequency::Monthly if self.skip == Skip::Forward => period - 1..=period,
+            Frequency::Monthly if self.skip == Skip::Backward => period..=period + 1,
+            _ => period..=period,
+        };
+
+        let interval = i64::from(self.interval);
+        periods
+            .filter(|period| *period >= 0 && period % interval == 0)
+            .map(|period| {
+                if period == 0 {
+                    return dt_start;
+                }
+                let period_start = match self.freq {
+                    Frequency::Yearly => i32::try_from(period)
+                        .ok()
+                        .and_then(|years| NaiveDate::from_ymd_opt(start.year() + years, 1, 1))
+                        .map(|date| date.and_time(NaiveTime::MIN)),
+                    Frequency::Monthly => u32::try_from(period).ok().and_then(|months| {
+                        start
+                            .date()
+                            .with_day(1)?
+                            .checked_add_months(Months::new(months))
+                            .map(|date| date.and_time(NaiveTime::MIN))
+                    }),
+                    Frequency::Weekly => Some(
+                        (week_start(start.date()) + Duration::weeks(period))
+                            .and_time(NaiveTime::MIN),
+                    ),
+                    Frequency::Daily => {
+                        Some((start.date() + Duration::days(period)).and_time(NaiveTime::MIN))
+                    }
+                    Frequency::Hourly => Some(truncate(start, 3600) + Duration::hours(period)),
+                    Frequency::Minutely => Some(truncate(start, 60) + Duration::minutes(period)),
+                    Frequency::Secondly => Some(start + Duration::seconds(period)),
+                };
+                // Iterate from `dt_start` if the period doesn't start at a valid local time
+                period_start
+                    .and_then(|period_start| tz.from_local_datetime(&period_start).earliest())
+                    .unwrap_or(dt_start)
+            })
+            .collect()
+    }
+
+    /// Returns `false` if the local `dt` can't be a recurrence, because of the month,
+    /// day, weekday or time BYxxx rule parts.
+    fn matches_rule_parts(&self, dt: &DateTime) -> bool {
+        let allows = |values: &[u8], value: u8| values.is_empty() || values.contains(&value);
+        if !allows(&self.by_month, get_month(dt))
+            || !allows(&self.by_hour, get_hour(dt))
+            || !allows(&self.by_minute, get_minute(dt))
+            || !allows(&self.by_second, get_second(dt))
+        {
+            return false;
+        }
+        if !self.by_weekday.is_empty()
+            && !self.by_weekday.iter().any(|weekday| match weekday {
+                NWeekday::Every(weekday) | NWeekday::Nth(_, weekday) => *weekday == dt.weekday(),
+            })
+        {
+            return false;
+        }
+        // Days moved by `SKIP`, or generated by `BYEASTER`, don't have to match `BYMONTHDAY`
+        if self.skip == Skip::Omit
+            && self.by_easter.is_none()
+            && (!self.by_month_day.is_empty() || !self.by_n_month_day.is_empty())
+        {
+            let day = get_day(dt);
+            let days_in_month = dt
+                .date_naive()
+                .with_day(1)
+                .and_then(|first| first.checked_add_months(Months::new(1)))
+                .and_then(|next_month| next_month.pred_opt())
+                .map_or(31, |last| last.day());
+            let from_end = i64::from(day) - i64::from(days_in_month) - 1;
+            if !self.by_month_day.contains(&day)
+                && !self
+                    .by_n_month_day
+                    .iter()
+                    .any(|n_month_day| i64::from(*n_month_day) == from_end)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Validates the rrule again with `new_dt_start` instead of `dt_start`.
+    ///
+    /// Rule parts that validation derived from `dt_start`, like the `BYHOUR` of a daily rule,
+    /// are derived from `new_dt_start` instead. Other rule parts are kept as they are.
+    pub(crate) fn revalidate(
+        &self,
+        dt_start: &DateTime,
+        new_dt_start: DateTime,
+    ) -> Result<Self, RRuleError> {
+        let mut rrule = RRule::<Unvalidated> {
+            freq: self.freq,
+            interval: self.interval,
+            count: self.count,
+            until: self.until,
+            week_start: self.week_start,
+            by_set_pos: self.by_set_pos.clone(),
+            by_month: self.by_month.clone(),
+            by_month_day: self.by_month_day.clone(),
+            by_n_month_day: self.by_n_month_day.clone(),
+            by_year_day: self.by_year_day.clone(),
+            by_week_no: self.by_week_no.clone(),
+            by_weekday: self.by_weekday.clone(),
+            by_hour: self.by_hour.clone(),
+            by_minute: self.by_minute.clone(),
+            by_second: self.by_second.clone(),
+            by_easter: self.by_easter,
+            skip: self.skip,
+            stage: PhantomData,
+        };
+
+        if !rrule.freq.is_sub_daily() && rrule.by_hour == [get_hour(dt_start)] {
+            rrule.by_hour.clear();
+        }
+        if rrule.freq < Frequency::Minutely && rrule.by_minute == [get_minute(dt_start)] {
+            rrule.by_minute.clear();
+        }
+        if rrule.freq < Frequency::Secondly && rrule.by_second == [get_second(dt_start)] {
+            rrule.by_second.clear();
+        }
+
+        let has_other_day_parts = !rrule.by_week_no.is_empty()
+            || !rrule.by_year_day.is_empty()
+            || !rrule.by_n_month_day.is_empty()
+            || rrule.by_easter.is_some();
+        let by_start_day = rrule.by_month_day == [get_day(dt_start)];
+        match rrule.freq {
+            Frequency::Yearly
+                if !has_other_day_parts
+                    && rrule.by_weekday.is_empty()
+                    && by_start_day
+                    && rrule.by_month == [get_month(dt_start)] =>
+            {
+                rrule.by_month.clear();
+                rrule.by_month_day.clear();
+            }
+            Frequency::Monthly
+                if !has_other_day_parts && rrule.by_weekday.is_empty() && by_start_day =>
+            {
+                rrule.by_month_day.clear();
+            }
+            Frequency::Weekly
+                if !has_other_day_parts
+                    && rrule.by_month_day.is_empty()
+                    && rrule.by_weekday == [NWeekday::Every(dt_start.weekday())] =>
+            {
+                rrule.by_weekday.clear();
+            }
+            _ => (),
+        }
+
+        rrule.validate(new_dt_start)
+    }
+
     pub(crate) fn iter_with_ctx(&self, dt_start: DateTime, limited: bool) -> RRuleIter {
         RRuleIter::new(self, &dt_start, limited)
     }
 }
 
+impl TryFrom<(RRule<Unvalidated>, DateTime)> for RRule<Validated> {
+    type Error = RRuleError;
+
+    /// Same as [`RRule::validate`].
+    fn try_from((rrule, dt_start): (RRule<Unvalidated>, DateTime)) -> Result<Self, Self::Error> {
+        rrule.validate(dt_start)
+    }
+}
+
+impl TryFrom<(RRule<Unvalidated>, DateTime)> for RRuleSet {
+    type Error = RRuleError;
+
+    /// Same as [`RRule::build`].
+    fn try_from((rrule, dt_start): (RRule<Unvalidated>, DateTime)) -> Result<Self, Self::Error> {
+        rrule.build(dt_start)
+    }
+}
+
 impl FromStr for RRule<Unvalidated> {
     type Err = RRuleError;
 
@@ -625,6 +1097,31 @@ impl FromStr for RRule<Unvalidated> {
     }
 }
 
+impl FromStr for RRule<Validated> {
+    type Err = RRuleError;
+
+    /// Parses a single validated [`RRule`] from a `DTSTART` and a `RRULE` line.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if the input is invalid, if it contains more than one `RRULE`,
+    /// or if it contains `RDATE`, `EXRULE` or `EXDATE` properties.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rrule_set = RRuleSet::from_str(s)?;
+        if rrule_set.rrule.len() != 1
+            || !rrule_set.rdate.is_empty()
+            || !rrule_set.exrule.is_empty()
+            || !rrule_set.exdate.is_empty()
+        {
+            return Err(ParseError::ExpectedSingleRRule.into());
+        }
+        rrule_set
+            .rrule
+            .pop()
+            .ok_or_else(|| ParseError::ExpectedSingleRRule.into())
+    }
+}
+
 impl<S> Display for RRule<S> {
     /// Generates a string based on the [iCalendar RRULE spec](https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.5.3).
     /// It doesn't prepend "RRULE:" to the string.
@@ -654,6 +1151,12 @@ impl<S> Display for RRule<S> {
             res.push(format!("INTERVAL={}", &self.interval));
         }
 
+        // Omitting invalid dates is the default, no need to expose it.
+        // `SKIP` is only allowed together with `RSCALE`.
+        if self.skip != Skip::Omit {
+            res.push(format!("RSCALE=GREGORIAN;SKIP={}", self.skip));
+        }
+
         // Monday is the default, no need to expose it.
         if self.week_start != Weekday::Mon {
             res.push(format!("WKST={}", weekday_to_str(self.week_start)));
@@ -681,11 +1184,13 @@ impl<S> Display for RRule<S> {
             ));
         }
 
-        if !self.by_month_day.is_empty() {
+        // Validation moves the negative days to `by_n_month_day`
+        if !self.by_month_day.is_empty() || !self.by_n_month_day.is_empty() {
             res.push(format!(
                 "BYMONTHDAY={}",
                 self.by_month_day
                     .iter()
+                    .chain(&self.by_n_month_day)
                     .map(ToString::to_string)
                     .collect::<Vec<_>>()
                     .join(",")
@@ -768,6 +1273,80 @@ impl<S> Display for RRule<S> {
 }
 
 impl<S> RRule<S> {
+    /// Describes the rrule in English, e.g. `every week on Monday for 3 times`.
+    ///
+    /// Only the frequency, interval, `BYMONTH`, `BYMONTHDAY`, `BYDAY`, `COUNT` and `UNTIL`
+    /// are described. Note that a validated rrule includes the parts that were
+    /// filled in from the start datetime.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRule, Unvalidated};
+    ///
+    /// let rrule: RRule<Unvalidated> = "FREQ=MONTHLY;INTERVAL=2;BYDAY=1MO,-1FR;COUNT=3".parse().unwrap();
+    /// assert_eq!(
+    ///     rrule.to_text(),
+    ///     "every 2 months on 1st Monday and last Friday for 3 times"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        self.to_text_with(&English)
+    }
+
+    /// Describes the rrule in the given [`Language`], see [`RRule::to_text`].
+    #[must_use]
+    pub fn to_text_with(&self, language: &impl Language) -> String {
+        let mut parts = vec![language.every(self.freq, self.interval)];
+
+        if !self.by_month.is_empty() {
+            let months = self
+                .by_month
+                .iter()
+                .map(|month| language.month(*month))
+                .collect::<Vec<_>>();
+            parts.push(language.in_months(&language.list(&months)));
+        }
+
+        // Days counted from the start of the month come first
+        let (mut month_days, negative_month_days): (Vec<_>, Vec<_>) = self
+            .by_month_day
+            .iter()
+            .chain(&self.by_n_month_day)
+            .map(|day| i16::from(*day))
+            .partition(|day| *day > 0);
+        month_days.extend(negative_month_days);
+        let month_days = month_days
+            .into_iter()
+            .map(|day| language.ordinal(day))
+            .collect::<Vec<_>>();
+        if !month_days.is_empty() {
+            parts.push(language.on_month_days(&language.list(&month_days)));
+        }
+
+        if !self.by_weekday.is_empty() {
+            let weekdays = self
+                .by_weekday
+                .iter()
+                .map(|weekday| match weekday {
+                    NWeekday::Every(weekday) => language.weekday(*weekday),
+                    NWeekday::Nth(n, weekday) => language.nth_weekday(*n, *weekday),
+                })
+                .collect::<Vec<_>>();
+            parts.push(language.on_weekdays(&language.list(&weekdays)));
+        }
+
+        if let Some(count) = self.count {
+            parts.push(language.count(count));
+        }
+        if let Some(until) = &self.until {
+            parts.push(language.until(until));
+        }
+
+        parts.join(" ")
+    }
+
     /// Get the frequency of the recurrence.
     #[must_use]
     pub fn get_freq(&self) -> Frequency {
@@ -792,13 +1371,21 @@ impl<S> RRule<S> {
         self.until.as_ref()
     }
 
-    /// Get the `by_set_pos` of the recurrence.
+    /// Get how invalid dates of the recurrence are handled.
+    /// Defaults to [`Skip::Omit`] if `SKIP` isn't set.
+    #[must_use]
+    pub fn get_skip(&self) -> Skip {
+        self.skip
+    }
+
+    /// Get the week start of the recurrence.
+    /// Defaults to [`Weekday::Mon`] if `WKST` isn't set.
     #[must_use]
     pub fn get_week_start(&self) -> Weekday {
         self.week_start
     }
 
-    /// Get the `by_month` of the recurrence.
+    /// Get the `by_set_pos` of the recurrence.
     #[must_use]
     pub fn get_by_set_pos(&self) -> &[i32] {
         &self.by_set_pos
@@ -859,3 +1446,19 @@ impl<S> RRule<S> {
         self.by_easter.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Frequency;
+
+    #[test]
+    fn frequency_is_sub_daily() {
+        assert!(!Frequency::Yearly.is_sub_daily());
+        assert!(!Frequency::Monthly.is_sub_daily());
+        assert!(!Frequency::Weekly.is_sub_daily());
+        assert!(!Frequency::Daily.is_sub_daily());
+        assert!(Frequency::Hourly.is_sub_daily());
+        assert!(Frequency::Minutely.is_sub_daily());
+        assert!(Frequency::Secondly.is_sub_daily());
+    }
+}
@@ -1,12 +1,21 @@
-use crate::core::datetime::datetime_to_ical_format;
-use crate::core::utils::collect_with_error;
+use crate::core::datetime::{date_to_ical_format, datetime_to_ical_format};
+use crate::core::utils::{
+    collect_into_with_error, collect_with_error, has_reached_the_end, is_in_range,
+};
 use crate::core::DateTime;
-use crate::parser::{ContentLine, Grammar};
-use crate::{ParseError, RRule, RRuleError};
+use crate::iter::rrule_iter::WasLimited;
+use crate::parser::{ContentLine, Grammar, ParseContext};
+use crate::{
+    ParseError, RRule, RRuleError, RRuleSetIter, RecurrenceSnapshot, Tz, Unvalidated,
+    ValidationError,
+};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday};
 #[cfg(feature = "serde")]
 use serde_with::{serde_as, DeserializeFromStr, SerializeDisplay};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::UNIX_EPOCH;
 
 /// A validated Recurrence Rule that can be used to create an iterator.
 #[cfg_attr(feature = "serde", serde_as)]
@@ -29,6 +38,12 @@ pub struct RRuleSet {
     pub(crate) after: Option<DateTime>,
     /// If validation limits are enabled
     pub(crate) limited: bool,
+    /// The duration of each recurrence, if set.
+    pub(crate) duration: Option<Duration>,
+    /// If the start date was parsed from a `DTSTART;VALUE=DATE`.
+    pub(crate) dt_start_is_date: bool,
+    /// If excluded recurrences count towards the `COUNT` of an rrule.
+    pub(crate) count_includes_excluded: bool,
 }
 
 /// The return result of `RRuleSet::all`.
@@ -41,6 +56,28 @@ pub struct RRuleResult {
     pub limited: bool,
 }
 
+/// A recurrence with its start and end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// Start of the recurrence.
+    pub start: DateTime,
+    /// End of the recurrence.
+    pub end: DateTime,
+}
+
+/// Why a datetime is or isn't a recurrence of an [`RRuleSet`], see [`RRuleSet::explain`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Explanation {
+    /// The datetime is not generated by any rrule or rdate.
+    NotGenerated,
+    /// The datetime is generated and not excluded, so it is a recurrence.
+    Included,
+    /// The datetime is generated, but excluded by an exdate.
+    ExcludedByExDate,
+    /// The datetime is generated, but excluded by an exrule.
+    ExcludedByExRule,
+}
+
 impl RRuleSet {
     /// Creates an empty [`RRuleSet`], starting from `ds_start`.
     #[must_use]
@@ -54,6 +91,9 @@ impl RRuleSet {
             before: None,
             after: None,
             limited: false,
+            duration: None,
+            dt_start_is_date: false,
+            count_includes_excluded: true,
         }
     }
 
@@ -66,6 +106,16 @@ impl RRuleSet {
         self
     }
 
+    /// Sets whether recurrences removed by an exdate or exrule count towards the `COUNT` of an rrule.
+    ///
+    /// Defaults to `true`, as the RFC applies `COUNT` before exclusions. When `false`,
+    /// an rrule with `COUNT=5` returns 5 recurrences that are not excluded.
+    #[must_use]
+    pub fn count_includes_excluded(mut self, count_includes_excluded: bool) -> Self {
+        self.count_includes_excluded = count_includes_excluded;
+        self
+    }
+
     /// Only return recurrences that comes before this `DateTime`.
     ///
     /// This value will not be used if you use the `Iterator` API directly.
@@ -77,6 +127,9 @@ impl RRuleSet {
 
     /// Only return recurrences that comes after this `DateTime`.
     ///
+    /// The crate never reads the system clock, so to find the upcoming
+    /// recurrences pass the current time explicitly.
+    ///
     /// This value will not be used if you use the `Iterator` API directly.
     #[must_use]
     pub fn after(mut self, dt: DateTime) -> Self {
@@ -91,6 +144,34 @@ impl RRuleSet {
         self
     }
 
+    /// Parses an rrule, with or without the `RRULE:` property name, validates it against
+    /// the start datetime of the set and adds it to the set.
+    ///
+    /// This is useful when the `DTSTART` and `RRULE` are stored separately.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let mut rrule_set = RRuleSet::new(Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap());
+    /// rrule_set.add_rrule_str("FREQ=DAILY;COUNT=3").unwrap();
+    ///
+    /// assert_eq!(rrule_set.all(10).dates.len(), 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if the rrule can't be parsed or is invalid.
+    pub fn add_rrule_str(&mut self, rrule: &str) -> Result<(), RRuleError> {
+        let rrule = rrule
+            .parse::<RRule<Unvalidated>>()?
+            .validate(self.dt_start)?;
+        self.rrule.push(rrule);
+        Ok(())
+    }
+
     /// Adds a new exrule to the set.
     #[must_use]
     #[cfg(feature = "exrule")]
@@ -113,6 +194,40 @@ impl RRuleSet {
         self
     }
 
+    /// Adds the exdates to the set, like [`RRuleSet::add_exdates`].
+    #[must_use]
+    pub fn with_exdates<I: IntoIterator<Item = DateTime>>(mut self, exdates: I) -> Self {
+        self.add_exdates(exdates);
+        self
+    }
+
+    /// Adds the exdates to the set, skipping the ones which are already excluded.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone};
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let dt_start = Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap();
+    /// let mut rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=10"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// rrule_set.add_exdates((0..5).map(|day| dt_start + Duration::days(day)));
+    /// rrule_set.add_exdates([dt_start]);
+    /// assert_eq!(rrule_set.get_exdate().len(), 5);
+    /// assert_eq!(rrule_set.all(100).dates.len(), 5);
+    /// ```
+    pub fn add_exdates<I: IntoIterator<Item = DateTime>>(&mut self, exdates: I) {
+        let mut existing = self.exdate.iter().copied().collect::<HashSet<_>>();
+        for exdate in exdates {
+            if existing.insert(exdate) {
+                self.exdate.push(exdate);
+            }
+        }
+    }
+
     /// Sets the rrules of the set.
     #[must_use]
     pub fn set_rrules(mut self, rrules: Vec<RRule>) -> Self {
@@ -142,6 +257,72 @@ impl RRuleSet {
         self
     }
 
+    /// Sets the duration of each recurrence, used by [`RRuleSet::expand_to_events`].
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Returns a copy of the set with its rrules replaced by `rrules`.
+    ///
+    /// The rrules are validated against the start datetime of the set,
+    /// all the other properties are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if any of the rrules is invalid.
+    pub fn clone_with_rules(&self, rrules: Vec<RRule<Unvalidated>>) -> Result<Self, RRuleError> {
+        let rrules = rrules
+            .into_iter()
+            .map(|rrule| rrule.validate(self.dt_start))
+            .collect::<Result<_, _>>()?;
+        Ok(self.clone().set_rrules(rrules))
+    }
+
+    /// Returns a copy of the set with `DTSTART`, the rdates and the exdates moved by `delta`.
+    ///
+    /// The rrules and exrules are validated again with the new start datetime, so rule
+    /// parts which were derived from `DTSTART`, like the time of a daily rule, move along.
+    /// Other rule parts, including `UNTIL`, are kept as they are.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=2"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dates = rrule_set.shift(Duration::minutes(30)).unwrap().all(10).dates;
+    /// assert_eq!(dates[1].to_rfc3339(), "2021-01-02T09:30:00+00:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`] if any of the rules is invalid with the new start datetime.
+    pub fn shift(&self, delta: Duration) -> Result<Self, RRuleError> {
+        let dt_start = self.dt_start + delta;
+        let revalidate = |rrules: &[RRule]| {
+            rrules
+                .iter()
+                .map(|rrule| rrule.revalidate(&self.dt_start, dt_start))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(Self {
+            rrule: revalidate(&self.rrule)?,
+            rdate: self.rdate.iter().map(|rdate| *rdate + delta).collect(),
+            exrule: revalidate(&self.exrule)?,
+            exdate: self.exdate.iter().map(|exdate| *exdate + delta).collect(),
+            dt_start,
+            dt_start_is_date: self.dt_start_is_date && delta.num_seconds() % (24 * 60 * 60) == 0,
+            ..self.clone()
+        })
+    }
+
     /// Returns the rrules of the set.
     #[must_use]
     pub fn get_rrule(&self) -> &Vec<RRule> {
@@ -172,6 +353,96 @@ impl RRuleSet {
         &self.dt_start
     }
 
+    /// Returns the duration of each recurrence, if set.
+    #[must_use]
+    pub fn get_duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Returns `true` if the recurrences are all-day events, without a meaningful time.
+    ///
+    /// This is the case when `DTSTART` is a date, like `DTSTART;VALUE=DATE:19970902`,
+    /// and no rrule has recurrences at other times than midnight, for example because
+    /// it has an hourly frequency or a `BYHOUR`. A `DTSTART` with a time at midnight,
+    /// like `DTSTART:19970902T000000Z`, is not all-day.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=WEEKLY".parse().unwrap();
+    /// assert!(rrule_set.is_all_day());
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:19970902T000000Z\nRRULE:FREQ=WEEKLY".parse().unwrap();
+    /// assert!(!rrule_set.is_all_day());
+    /// ```
+    #[must_use]
+    pub fn is_all_day(&self) -> bool {
+        self.dt_start_is_date
+            && self.rrule.iter().all(|rrule| {
+                !rrule.freq.is_sub_daily()
+                    && [&rrule.by_hour, &rrule.by_minute, &rrule.by_second]
+                        .iter()
+                        .all(|values| values.iter().all(|value| *value == 0))
+            })
+    }
+
+    /// Returns a plain copy of the contents of the set, see [`RecurrenceSnapshot`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{Frequency, RRuleSet};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=3"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let snapshot = rrule_set.snapshot();
+    /// assert_eq!(snapshot.rrules[0].freq, Frequency::Daily);
+    /// assert_eq!(snapshot.rrules[0].count, Some(3));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> RecurrenceSnapshot {
+        self.into()
+    }
+
+    /// Returns `true` if the set has no rrules, rdates, exrules or exdates.
+    ///
+    /// This only looks at the parts of the set, not at the recurrences they produce.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rrule.is_empty()
+            && self.rdate.is_empty()
+            && self.exrule.is_empty()
+            && self.exdate.is_empty()
+    }
+
+    /// Returns a short description of the parts of the set,
+    /// e.g. `1 RRULE, 2 RDATE, 0 EXRULE, 1 EXDATE`.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        format!(
+            "{} RRULE, {} RDATE, {} EXRULE, {} EXDATE",
+            self.rrule.len(),
+            self.rdate.len(),
+            self.exrule.len(),
+            self.exdate.len()
+        )
+    }
+
+    /// Returns the iCalendar lines of the set, like [`RRuleSet::to_string`], but folded
+    /// so that no line is longer than 75 octets.
+    ///
+    /// Each item is a physical line, without the line break. Continuation lines start with
+    /// a space, so joining the items with `\r\n` results in valid iCalendar content.
+    /// See [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1) for more info.
+    #[must_use]
+    pub fn to_ical_lines(&self) -> Vec<String> {
+        self.to_string().lines().flat_map(fold_line).collect()
+    }
+
     /// Returns all the recurrences of the rrule.
     ///
     /// Limit must be set in order to prevent infinite loops.
@@ -193,7 +464,7 @@ impl RRuleSet {
     pub fn all(mut self, limit: u16) -> RRuleResult {
         self.limited = true;
         collect_with_error(
-            self.into_iter(),
+            self.iter_from_after(self.limited),
             &self.after,
             &self.before,
             true,
@@ -201,6 +472,260 @@ impl RRuleSet {
         )
     }
 
+    /// Replaces the content of `buf` with the recurrences of the rrule, like [`RRuleSet::all`].
+    ///
+    /// This allows a single buffer to be reused, for example when expanding many sets in a loop.
+    /// Returns `true` if the result was limited, like [`RRuleResult::limited`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let mut buf = Vec::new();
+    /// for rrule in ["FREQ=DAILY;COUNT=3", "FREQ=WEEKLY;COUNT=2"] {
+    ///     let rrule_set: RRuleSet = format!("DTSTART:20210101T090000Z\nRRULE:{rrule}")
+    ///         .parse()
+    ///         .unwrap();
+    ///     rrule_set.all_collect_into(&mut buf, 100);
+    ///     println!("{buf:?}");
+    /// }
+    /// assert_eq!(buf.len(), 2);
+    /// ```
+    pub fn all_collect_into(&self, buf: &mut Vec<DateTime>, limit: u16) -> bool {
+        buf.clear();
+        collect_into_with_error(
+            self.iter_from_after(true),
+            &self.after,
+            &self.before,
+            true,
+            Some(limit),
+            buf,
+        )
+    }
+
+    /// Returns all the recurrences of the rrule as wall clock times in the timezone of `DTSTART`.
+    ///
+    /// Limit must be set in order to prevent infinite loops, see [`RRuleSet::all`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20210101T090000\nRRULE:FREQ=DAILY"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dates = rrule_set.all_naive_local(2);
+    /// assert_eq!(dates[1].to_string(), "2021-01-02 09:00:00");
+    /// ```
+    #[must_use]
+    pub fn all_naive_local(&self, limit: u16) -> Vec<NaiveDateTime> {
+        let tz = self.dt_start.timezone();
+        self.clone()
+            .all(limit)
+            .dates
+            .into_iter()
+            .map(|dt| dt.with_timezone(&tz).naive_local())
+            .collect()
+    }
+
+    /// Returns the dates of all the recurrences in the timezone of `DTSTART`, like
+    /// [`RRuleSet::all_checked`], which is useful for all-day events.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;VALUE=DATE:20210101\nRRULE:FREQ=WEEKLY;COUNT=2"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dates = rrule_set.all_dates(10).unwrap();
+    /// assert_eq!(dates[1].to_string(), "2021-01-08");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`RRuleSet::all_checked`].
+    pub fn all_dates(&self, limit: u16) -> Result<Vec<NaiveDate>, RRuleError> {
+        let tz = self.dt_start.timezone();
+        Ok(self
+            .clone()
+            .all_checked(limit)?
+            .into_iter()
+            .map(|dt| dt.with_timezone(&tz).date_naive())
+            .collect())
+    }
+
+    /// Returns all the recurrences of the rrule converted to the timezone `tz`, which doesn't
+    /// need to be the timezone of `DTSTART`.
+    ///
+    /// The recurrences are still computed in the timezone of `DTSTART`, only the result is
+    /// converted. Limit must be set in order to prevent infinite loops, see [`RRuleSet::all`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART;TZID=Europe/London:20210101T090000\nRRULE:FREQ=DAILY"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dates = rrule_set.all_in_timezone(Tz::Asia__Tokyo, 2);
+    /// assert_eq!(dates[1].to_string(), "2021-01-02 18:00:00 JST");
+    /// ```
+    #[must_use]
+    pub fn all_in_timezone(&self, tz: Tz, limit: u16) -> Vec<DateTime> {
+        self.clone()
+            .all(limit)
+            .dates
+            .into_iter()
+            .map(|dt| dt.with_timezone(&tz))
+            .collect()
+    }
+
+    /// Returns all the recurrences of the rrule, like [`RRuleSet::all`],
+    /// but fails instead of silently truncating the result at `limit`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRuleError, RRuleSet};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=3".parse().unwrap();
+    ///
+    /// assert_eq!(rrule_set.clone().all_checked(3).map(|dates| dates.len()), Ok(3));
+    /// assert_eq!(rrule_set.all_checked(2), Err(RRuleError::LimitReached));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::LimitReached`] if there are more than `limit` recurrences,
+    /// or [`RRuleError::IterError`] if the validation limits were reached before the end
+    /// of the recurrences.
+    pub fn all_checked(self, limit: u16) -> Result<Vec<DateTime>, RRuleError> {
+        let limit = usize::from(limit);
+        let mut iter = self.iter_from_after(true);
+        let mut dates = vec![];
+        for date in iter.by_ref() {
+            if has_reached_the_end(&date, &self.before, true) {
+                return Ok(dates);
+            }
+            if is_in_range(&date, &self.after, &self.before, true) {
+                // One more recurrence than requested means the limit was too small
+                if dates.len() == limit {
+                    return Err(RRuleError::LimitReached);
+                }
+                dates.push(date);
+            }
+        }
+        if iter.was_limited() {
+            return Err(RRuleError::new_iter_err(
+                "Reached the validation limits before the end of the recurrences.",
+            ));
+        }
+        Ok(dates)
+    }
+
+    /// Returns all the recurrences of the rrule as [`Event`]s, which have an end as well as a start.
+    ///
+    /// The end is the start plus `duration`, or the duration of the set if `duration` is `None`.
+    /// If neither is set, events with a date-only `DTSTART` (`VALUE=DATE`) end at the start
+    /// of the next day in the timezone of `DTSTART`, and other events end at their start.
+    ///
+    /// Limit must be set in order to prevent infinite loops, see [`RRuleSet::all`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    ///
+    /// let events = rrule_set.expand_to_events(2, Some(Duration::minutes(30)));
+    /// assert_eq!(events.len(), 2);
+    /// assert_eq!(events[0].end - events[0].start, Duration::minutes(30));
+    /// ```
+    #[must_use]
+    pub fn expand_to_events(&self, limit: u16, duration: Option<Duration>) -> Vec<Event> {
+        self.clone()
+            .all(limit)
+            .dates
+            .into_iter()
+            .map(|start| Event {
+                start,
+                end: self.event_end(start, duration),
+            })
+            .collect()
+    }
+
+    /// Returns the recurrences of the rrule as [`Event`]s which overlap with the window
+    /// from `after` to `before`, both inclusive.
+    ///
+    /// Unlike [`RRuleSet::after`] and [`RRuleSet::before`], this also returns events which
+    /// start before `after`, but haven't ended yet. The duration of the events is the same
+    /// as for [`RRuleSet::expand_to_events`] without a `duration`.
+    /// The validation limits are always enabled, see [`RRuleSet::limit`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone};
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY"
+    ///     .parse::<RRuleSet>()
+    ///     .unwrap()
+    ///     .duration(Duration::hours(2));
+    ///
+    /// let events = rrule_set.all_events_between(
+    ///     Tz::UTC.with_ymd_and_hms(2021, 1, 2, 10, 0, 0).unwrap(),
+    ///     Tz::UTC.with_ymd_and_hms(2021, 1, 3, 8, 0, 0).unwrap(),
+    /// );
+    /// assert_eq!(events.len(), 1);
+    /// assert_eq!(events[0].start.to_rfc3339(), "2021-01-02T09:00:00+00:00");
+    /// ```
+    #[must_use]
+    pub fn all_events_between(&self, after: DateTime, before: DateTime) -> Vec<Event> {
+        let rrule_set = self.clone().limit();
+        rrule_set
+            .all_iter()
+            .map(|start| Event {
+                start,
+                end: self.event_end(start, None),
+            })
+            .skip_while(|event| event.end < after)
+            .take_while(|event| event.start <= before)
+            .collect()
+    }
+
+    /// Returns the end of the event starting at `start`, which lasts the given duration,
+    /// or the default duration of the events of the set.
+    fn event_end(&self, start: DateTime, duration: Option<Duration>) -> DateTime {
+        if let Some(duration) = duration.or(self.duration) {
+            return start + duration;
+        }
+        if !self.dt_start_is_date {
+            return start;
+        }
+        // A day isn't always 24 hours long, because of daylight saving time
+        start
+            .date_naive()
+            .succ_opt()
+            .and_then(|date| {
+                start
+                    .timezone()
+                    .from_local_datetime(&date.and_time(NaiveTime::MIN))
+                    .earliest()
+            })
+            .unwrap_or_else(|| start + Duration::days(1))
+    }
+
     /// Returns all the recurrences of the rrule.
     ///
     /// # Note
@@ -209,18 +734,485 @@ impl RRuleSet {
     /// very long iteration times. Please read the `SECURITY.md` for more information.
     #[must_use]
     pub fn all_unchecked(self) -> Vec<DateTime> {
-        collect_with_error(self.into_iter(), &self.after, &self.before, true, None).dates
+        collect_with_error(
+            self.iter_from_after(self.limited),
+            &self.after,
+            &self.before,
+            true,
+            None,
+        )
+        .dates
+    }
+
+    /// Returns an iterator which skips ahead to the `after` value, if possible.
+    ///
+    /// Dates before `after` can still be returned, so they have to be filtered out.
+    fn iter_from_after(&self, limited: bool) -> RRuleSetIter {
+        let mut iter = self.iter_with_ctx(limited);
+        if let Some(after) = &self.after {
+            iter.skip_before(after);
+        }
+        iter
+    }
+
+    /// Returns a lazy iterator over all the recurrences of the rrule.
+    ///
+    /// This is the same as calling `into_iter` on a reference to the set, but reads better
+    /// when chaining iterator adaptors.
+    ///
+    /// # Note
+    ///
+    /// The `after` and `before` values are not applied to the iterator, and validation
+    /// limits are only enforced if they were enabled with [`RRuleSet::limit`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::{Datelike, Weekday};
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    ///
+    /// // The first 3 Mondays
+    /// let mondays = rrule_set
+    ///     .all_iter()
+    ///     .filter(|dt| dt.weekday() == Weekday::Mon)
+    ///     .take(3)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(mondays.len(), 3);
+    /// assert_eq!(mondays[0].day(), 4);
+    /// assert_eq!(mondays[2].day(), 18);
+    /// ```
+    #[must_use]
+    pub fn all_iter(&self) -> RRuleSetIter {
+        self.into_iter()
     }
 
-    fn set_from_content_lines(self, content_lines: Vec<ContentLine>) -> Result<Self, RRuleError> {
+    /// Returns an iterator over all the recurrences of the set, which owns the set.
+    ///
+    /// Unlike [`RRuleSet::all_iter`], the iterator doesn't borrow the set, so it can be
+    /// returned from a function. Like [`RRuleSet::all_iter`], the `after` and `before` values
+    /// are not applied. The validation limits are always enabled, see [`RRuleSet::limit`],
+    /// and if they are reached, an error is returned as the last item.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRuleError, RRuleSet, Tz};
+    ///
+    /// fn mondays() -> impl Iterator<Item = Result<chrono::DateTime<Tz>, RRuleError>> {
+    ///     let rrule_set: RRuleSet = "DTSTART:20210104T090000Z\nRRULE:FREQ=WEEKLY"
+    ///         .parse()
+    ///         .unwrap();
+    ///     rrule_set.occurrences_stream()
+    /// }
+    ///
+    /// let dates = mondays().take(2).collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(dates[1].to_rfc3339(), "2021-01-11T09:00:00+00:00");
+    /// ```
+    pub fn occurrences_stream(self) -> impl Iterator<Item = Result<DateTime, RRuleError>> {
+        let mut iter = self.iter_with_ctx(true);
+        let mut finished = false;
+        std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+            match iter.next() {
+                Some(date) => Some(Ok(date)),
+                None => {
+                    finished = true;
+                    iter.was_limited().then(|| {
+                        Err(RRuleError::new_iter_err(
+                            "Reached the validation limits before the end of the recurrences.",
+                        ))
+                    })
+                }
+            }
+        })
+    }
+
+    /// Returns a boxed iterator over all the recurrences of the set, which owns the set.
+    ///
+    /// This allows storing sets together with other sources of dates as
+    /// `Box<dyn Iterator>`. See [`RRuleSet::occurrences_stream`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::{RRuleError, RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    ///
+    /// let mut iter: Box<dyn Iterator<Item = Result<chrono::DateTime<Tz>, RRuleError>>> =
+    ///     rrule_set.into_boxed_iter();
+    /// assert_eq!(
+    ///     iter.next().unwrap().unwrap().to_rfc3339(),
+    ///     "2021-01-01T09:00:00+00:00"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn into_boxed_iter(self) -> Box<dyn Iterator<Item = Result<DateTime, RRuleError>>> {
+        Box::new(self.occurrences_stream())
+    }
+
+    /// Returns every `every`-th recurrence of the rrule, starting with the first one,
+    /// up to a total of `max` recurrences.
+    ///
+    /// This is useful to preview dense rules, like a minutely rule. An `every` of `0` is
+    /// treated as `1`. Like [`RRuleSet::all_iter`], the `after` and `before` values are not applied.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=MINUTELY".parse().unwrap();
+    ///
+    /// // Every 10th minute, 3 times
+    /// let dates = rrule_set.sample(10, 3);
+    /// assert_eq!(dates.len(), 3);
+    /// assert_eq!(dates[1].to_rfc3339(), "2021-01-01T09:10:00+00:00");
+    /// ```
+    #[must_use]
+    pub fn sample(&self, every: usize, max: usize) -> Vec<DateTime> {
+        self.all_iter().step_by(every.max(1)).take(max).collect()
+    }
+
+    /// Returns up to `limit` recurrences of the rrule for which `predicate` returns `true`.
+    ///
+    /// The predicate is applied while iterating, so recurrences are not collected up front.
+    /// Like [`RRuleSet::all_iter`], the `after` and `before` values are not applied.
+    /// If the predicate rarely matches on an infinite rule, this can iterate for a long time,
+    /// unless the iteration limits were enabled with [`RRuleSet::limit`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::{Datelike, Weekday};
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    ///
+    /// // The first 3 days which aren't in the weekend
+    /// let dates = rrule_set.occurrences_matching(
+    ///     |dt| !matches!(dt.weekday(), Weekday::Sat | Weekday::Sun),
+    ///     3,
+    /// );
+    /// assert_eq!(dates.len(), 3);
+    /// assert_eq!(dates[1].day(), 4);
+    /// ```
+    #[must_use]
+    pub fn occurrences_matching<F>(&self, mut predicate: F, limit: usize) -> Vec<DateTime>
+    where
+        F: FnMut(&DateTime) -> bool,
+    {
+        self.all_iter()
+            .filter(|dt| predicate(dt))
+            .take(limit)
+            .collect()
+    }
+
+    /// Returns up to `n` recurrences strictly after `reference`, for example the next
+    /// recurrences from now.
+    ///
+    /// Exdates and exrules are applied, but the `after` and `before` values are not.
+    /// The validation limits are always enabled, see [`RRuleSet::limit`]. The recurrences
+    /// before `reference` are skipped without generating them where possible.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    /// let now = Tz::UTC.with_ymd_and_hms(2021, 3, 1, 12, 0, 0).unwrap();
+    ///
+    /// let dates = rrule_set.next_n(now, 2).unwrap();
+    /// assert_eq!(dates[0].to_rfc3339(), "2021-03-02T09:00:00+00:00");
+    /// assert_eq!(dates[1].to_rfc3339(), "2021-03-03T09:00:00+00:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::IterError`] if the validation limits were reached
+    /// before `n` recurrences were found.
+    pub fn next_n(&self, reference: DateTime, n: usize) -> Result<Vec<DateTime>, RRuleError> {
+        let mut iter = self.iter_with_ctx(true);
+        iter.skip_before(&reference);
+        let dates = iter
+            .by_ref()
+            .skip_while(|dt| *dt <= reference)
+            .take(n)
+            .collect::<Vec<_>>();
+        if dates.len() < n && iter.was_limited() {
+            return Err(RRuleError::new_iter_err(
+                "Reached the validation limits before finding all recurrences.",
+            ));
+        }
+        Ok(dates)
+    }
+
+    /// Returns the `n`th recurrence after `reference`, counting from 0.
+    ///
+    /// This is the same as `next_n(reference, n + 1)` followed by taking the last date,
+    /// but doesn't collect the dates. Like [`RRuleSet::next_n`], the `after` and `before`
+    /// values are not applied and the validation limits are always enabled.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=WEEKLY".parse().unwrap();
+    /// let now = Tz::UTC.with_ymd_and_hms(2021, 3, 1, 12, 0, 0).unwrap();
+    ///
+    /// let date = rrule_set.nth_from(now, 4).unwrap();
+    /// assert_eq!(date.unwrap().to_rfc3339(), "2021-04-02T09:00:00+00:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::IterError`] if the validation limits were reached
+    /// before the recurrence was found.
+    pub fn nth_from(&self, reference: DateTime, n: usize) -> Result<Option<DateTime>, RRuleError> {
+        let mut iter = self.iter_with_ctx(true);
+        iter.skip_before(&reference);
+        let date = iter.by_ref().skip_while(|dt| *dt <= reference).nth(n);
+        if date.is_none() && iter.was_limited() {
+            return Err(RRuleError::new_iter_err(
+                "Reached the validation limits before finding the recurrence.",
+            ));
+        }
+        Ok(date)
+    }
+
+    /// Returns the first recurrence after `reference` which is not in `extra_exdates`.
+    ///
+    /// The `extra_exdates` are excluded in addition to the exdates and exrules of the set,
+    /// without being stored in it. This is useful to find the next recurrence which isn't
+    /// already taken, for example. Like [`RRuleSet::next_n`], the `after` and `before`
+    /// values are not applied and the validation limits are always enabled.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY".parse().unwrap();
+    /// let now = Tz::UTC.with_ymd_and_hms(2021, 3, 1, 12, 0, 0).unwrap();
+    /// let booked = [Tz::UTC.with_ymd_and_hms(2021, 3, 2, 9, 0, 0).unwrap()];
+    ///
+    /// let next = rrule_set.first_after_excluding(now, &booked).unwrap();
+    /// assert_eq!(next.unwrap().to_rfc3339(), "2021-03-03T09:00:00+00:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::IterError`] if the validation limits were reached
+    /// before a recurrence was found.
+    pub fn first_after_excluding(
+        &self,
+        reference: DateTime,
+        extra_exdates: &[DateTime],
+    ) -> Result<Option<DateTime>, RRuleError> {
+        let extra_exdates = extra_exdates
+            .iter()
+            .map(DateTime::timestamp)
+            .collect::<HashSet<_>>();
+        let mut iter = self.iter_with_ctx(true);
+        iter.skip_before(&reference);
+        let date = iter
+            .by_ref()
+            .skip_while(|dt| *dt <= reference)
+            .find(|dt| !extra_exdates.contains(&dt.timestamp()));
+        if date.is_none() && iter.was_limited() {
+            return Err(RRuleError::new_iter_err(
+                "Reached the validation limits before finding a recurrence.",
+            ));
+        }
+        Ok(date)
+    }
+
+    /// Returns `true` if both sets have the same first `limit` recurrences.
+    ///
+    /// Unlike `==`, this compares the recurrences instead of how the sets are written,
+    /// so a set with an `RRULE` can be equal to a set with the same dates as `RDATE`s.
+    /// Like [`RRuleSet::all_iter`], the `after` and `before` values are not applied.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=2".parse().unwrap();
+    /// let rdate: RRuleSet = "DTSTART:20210101T090000Z\nRDATE:20210101T090000Z,20210102T090000Z"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// assert_ne!(rrule, rdate);
+    /// assert!(rrule.occurrences_equal(&rdate, 10));
+    /// ```
+    #[must_use]
+    pub fn occurrences_equal(&self, other: &Self, limit: usize) -> bool {
+        self.all_iter().take(limit).eq(other.all_iter().take(limit))
+    }
+
+    /// Returns the duration from the first to the last recurrence of the set,
+    /// or `None` if the set has no recurrences.
+    ///
+    /// Like [`RRuleSet::all_iter`], the `after` and `before` values are not applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::InfiniteRule`] if any of the rrules has neither a `COUNT` nor an `UNTIL`.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3".parse().unwrap();
+    ///
+    /// assert_eq!(rrule_set.span().unwrap(), Some(Duration::weeks(2)));
+    /// ```
+    pub fn span(&self) -> Result<Option<Duration>, RRuleError> {
+        if self.has_infinite_rrule() {
+            return Err(RRuleError::InfiniteRule);
+        }
+
+        let mut iter = self.all_iter();
+        Ok(iter.next().map(|first| {
+            let last = iter.last().unwrap_or(first);
+            last - first
+        }))
+    }
+
+    /// Returns the number of recurrences on each weekday, in the timezone of `DTSTART`.
+    ///
+    /// Weekdays without recurrences are left out. Like [`RRuleSet::all_checked`],
+    /// the `after` and `before` values are applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::InfiniteRule`] if any of the rrules has neither a `COUNT` nor an `UNTIL`,
+    /// and otherwise fails like [`RRuleSet::all_checked`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=10".parse().unwrap();
+    ///
+    /// let histogram = rrule_set.weekday_histogram(100).unwrap();
+    /// assert_eq!(histogram[&Weekday::Fri], 2);
+    /// assert_eq!(histogram[&Weekday::Mon], 1);
+    /// ```
+    pub fn weekday_histogram(&self, limit: u16) -> Result<HashMap<Weekday, usize>, RRuleError> {
+        if self.has_infinite_rrule() {
+            return Err(RRuleError::InfiniteRule);
+        }
+
+        let mut histogram = HashMap::new();
+        for date in self.clone().all_checked(limit)? {
+            *histogram.entry(date.weekday()).or_default() += 1;
+        }
+        Ok(histogram)
+    }
+
+    /// Returns `true` if any of the rrules has neither a `COUNT` nor an `UNTIL`.
+    fn has_infinite_rrule(&self) -> bool {
+        self.rrule
+            .iter()
+            .any(|rrule| rrule.count.is_none() && rrule.until.is_none())
+    }
+
+    /// Explains why `dt` is or isn't a recurrence of the set.
+    ///
+    /// This is meant for debugging, as it iterates over every rrule and exrule up to `dt`.
+    /// Validation limits are always enabled, so a datetime that can only be reached by
+    /// hitting those limits is reported as [`Explanation::NotGenerated`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{Explanation, RRuleSet, Tz};
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY\nEXDATE:20210102T090000Z"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let dt = Tz::UTC.with_ymd_and_hms(2021, 1, 2, 9, 0, 0).unwrap();
+    /// assert_eq!(rrule_set.explain(dt), Explanation::ExcludedByExDate);
+    /// ```
+    #[must_use]
+    pub fn explain(&self, dt: DateTime) -> Explanation {
+        let generates = |rrule: &RRule| {
+            rrule
+                .iter_with_ctx(self.dt_start, true)
+                .take_while(|date| *date <= dt)
+                .any(|date| date == dt)
+        };
+
+        if !self.rdate.contains(&dt) && !self.rrule.iter().any(generates) {
+            Explanation::NotGenerated
+        } else if self
+            .exdate
+            .iter()
+            .any(|exdate| exdate.timestamp() == dt.timestamp())
+        {
+            Explanation::ExcludedByExDate
+        } else if self.exrule.iter().any(generates) {
+            Explanation::ExcludedByExRule
+        } else {
+            Explanation::Included
+        }
+    }
+
+    fn set_from_content_lines(
+        self,
+        content_lines: Vec<ContentLine>,
+        ctx: &mut ParseContext,
+    ) -> Result<Self, RRuleError> {
         let dt_start = self.dt_start;
 
+        // RFC 5545 doesn't allow rule parts with a time if `DTSTART` is a date
+        if self.dt_start_is_date
+            && content_lines.iter().any(|line| match line {
+                ContentLine::RRule(rrule) | ContentLine::ExRule(rrule) => {
+                    !rrule.by_hour.is_empty()
+                        || !rrule.by_minute.is_empty()
+                        || !rrule.by_second.is_empty()
+                }
+                ContentLine::ExDate(_) | ContentLine::RDate(_) => false,
+            })
+        {
+            return Err(RRuleError::TimeOnDateOnlyEvent);
+        }
+
         content_lines.into_iter().try_fold(
             self,
             |rrule_set, content_line| match content_line {
-                ContentLine::RRule(rrule) => rrule
-                    .validate(dt_start)
-                    .map(|rrule| rrule_set.rrule(rrule)),
+                ContentLine::RRule(rrule) => match rrule.validate(dt_start) {
+                    // In lenient mode, an rrule without recurrences is dropped
+                    Err(RRuleError::ValidationError(ValidationError::UntilBeforeStart {
+                        until,
+                        ..
+                    })) if ctx.lenient => {
+                        ctx.warn(format!(
+                            "Ignored an `RRULE` with `UNTIL` {until} before `DTSTART`, \
+                            it has no recurrences."
+                        ));
+                        Ok(rrule_set)
+                    }
+                    rrule => rrule.map(|rrule| rrule_set.rrule(rrule)),
+                },
                 #[allow(unused_variables)]
                 ContentLine::ExRule(exrule) => {
                     #[cfg(feature = "exrule")]
@@ -231,7 +1223,12 @@ impl RRuleSet {
                     }
                     #[cfg(not(feature = "exrule"))]
                     {
-                        log::warn!("Found EXRULE in input, but it will be ignored since the `exrule` feature is not enabled.");
+                        let warning = "Found EXRULE in input, but it will be ignored since the `exrule` feature is not enabled.";
+                        if ctx.lenient {
+                            ctx.warn(warning);
+                        } else {
+                            log::warn!("{}", warning);
+                        }
                         Ok(rrule_set)
                     }
                 }
@@ -245,6 +1242,43 @@ impl RRuleSet {
         )
     }
 
+    /// Adds the rrules, rdates, exrules and exdates of `other` to the set.
+    ///
+    /// All other properties, like `before` and `after`, are kept from this set.
+    /// Note that the exdates and exrules of both sets apply to the recurrences of both sets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::MergeDtStartMismatch`] if the sets have a different start datetime,
+    /// since the rrules are validated against it.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use rrule::RRuleSet;
+    ///
+    /// let rrule_set: RRuleSet = "DTSTART:20210101T090000Z\nRRULE:FREQ=DAILY;COUNT=2".parse().unwrap();
+    /// let other: RRuleSet = "DTSTART:20210101T090000Z\nRDATE:20210301T090000Z".parse().unwrap();
+    ///
+    /// let merged = rrule_set.merge(other).unwrap();
+    /// assert_eq!(merged.all(10).dates.len(), 3);
+    /// ```
+    pub fn merge(mut self, other: Self) -> Result<Self, RRuleError> {
+        if self.dt_start != other.dt_start {
+            return Err(ValidationError::MergeDtStartMismatch {
+                dt_start: self.dt_start.to_rfc3339(),
+                other_dt_start: other.dt_start.to_rfc3339(),
+            }
+            .into());
+        }
+
+        self.rrule.extend(other.rrule);
+        self.rdate.extend(other.rdate);
+        self.exrule.extend(other.exrule);
+        self.exdate.extend(other.exdate);
+        Ok(self)
+    }
+
     /// Set the [`RRuleSet`] properties from a string. If a DTSTART is found, it will be used as the start datetime.
     pub fn set_from_string(mut self, s: &str) -> Result<Self, RRuleError> {
         let Grammar {
@@ -253,10 +1287,71 @@ impl RRuleSet {
         } = Grammar::from_str(s)?;
 
         if let Some(dtstart) = start {
+            self.dt_start_is_date = dtstart.value == "DATE";
             self.dt_start = dtstart.datetime;
         }
 
-        self.set_from_content_lines(content_lines)
+        self.set_from_content_lines(content_lines, &mut ParseContext::default())
+    }
+
+    /// Creates an [`RRuleSet`] from a string, like [`RRuleSet::from_str`], but accepts some
+    /// commonly seen input which doesn't follow the RFC:
+    /// - A `DTSTART` with a numeric UTC offset, like `DTSTART:19970902T090000+0200`.
+    ///   The start date will be converted to UTC.
+    /// - Unknown rule parts in a `RRULE` or `EXRULE`, like vendor specific `X-CUSTOM=foo` parts.
+    ///   These are ignored.
+    /// - Whitespace around the names and values of rule parts, like in `FREQ=WEEKLY `.
+    /// - Rule parts separated by whitespace instead of `;`, like in `FREQ=DAILY COUNT=3`.
+    /// - Blank lines and the `BEGIN` and `END` lines of a component, like `BEGIN:VEVENT`.
+    ///   These are skipped.
+    /// - Multiple `DTSTART` properties. The first one is used.
+    /// - Lowercase property names, parameter values and datetimes, like `rrule:freq=weekly`
+    ///   or `DTSTART;VALUE=date:19970902`. Rule part names and values are case-insensitive,
+    ///   even without lenient parsing.
+    /// - `COUNT=0`, which some generators use for a rule without recurrences.
+    /// - A `RRULE` with an `UNTIL` before `DTSTART`, which has no recurrences. It is ignored.
+    ///
+    /// Returns the set together with a warning for every non-conforming part of the input
+    /// which was accepted, and for every deprecated `EXRULE` property. An `EXRULE` is only
+    /// applied if the `exrule` feature is enabled, otherwise the warning says it was ignored.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use chrono::TimeZone;
+    /// use rrule::{RRuleSet, Tz};
+    ///
+    /// let (rrule_set, warnings) =
+    ///     RRuleSet::from_str_lenient("DTSTART:19970902T090000+0200\nRRULE:FREQ=DAILY;COUNT=3")
+    ///         .unwrap();
+    ///
+    /// assert_eq!(
+    ///     *rrule_set.get_dt_start(),
+    ///     Tz::UTC.with_ymd_and_hms(1997, 9, 2, 7, 0, 0).unwrap()
+    /// );
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError`], if iCalendar string contains invalid parts.
+    pub fn from_str_lenient(s: &str) -> Result<(Self, Vec<String>), RRuleError> {
+        let mut ctx = ParseContext::lenient();
+        let rrule_set = Self::parse(s, &mut ctx)?;
+        Ok((rrule_set, ctx.warnings))
+    }
+
+    fn parse(s: &str, ctx: &mut ParseContext) -> Result<Self, RRuleError> {
+        let Grammar {
+            start,
+            content_lines,
+        } = Grammar::parse(s, ctx)?;
+
+        let start = start.ok_or(ParseError::MissingStartDate)?;
+
+        let mut set = Self::new(start.datetime);
+        set.dt_start_is_date = start.value == "DATE";
+        set.set_from_content_lines(content_lines, ctx)
     }
 }
 
@@ -265,31 +1360,64 @@ impl FromStr for RRuleSet {
 
     /// Creates an [`RRuleSet`] from a string if input is valid.
     ///
+    /// Parsing does not panic on any input, malformed input always results in an error.
+    ///
     /// # Errors
     ///
     /// Returns [`RRuleError`], if iCalendar string contains invalid parts.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Grammar {
-            start,
-            content_lines,
-        } = Grammar::from_str(s)?;
+        Self::parse(s, &mut ParseContext::default())
+    }
+}
 
-        let start = start.ok_or(ParseError::MissingStartDate)?;
+/// Formats `rrule` for a set, without the rule parts with a time if `DTSTART` is a date.
+///
+/// RFC 5545 doesn't allow those rule parts in that case, and they are always the time
+/// of `DTSTART`, so they are derived again when the set is parsed.
+fn rule_to_string(rrule: &RRule, dt_start_is_date: bool) -> String {
+    if !dt_start_is_date {
+        return rrule.to_string();
+    }
+    let mut rrule = rrule.clone();
+    rrule.by_hour.clear();
+    rrule.by_minute.clear();
+    rrule.by_second.clear();
+    rrule.to_string()
+}
+
+/// Maximum length of a physical iCalendar line in octets, without the line break.
+const MAX_LINE_OCTETS: usize = 75;
 
-        Self::new(start.datetime).set_from_content_lines(content_lines)
+/// Splits a line into physical lines of at most [`MAX_LINE_OCTETS`] octets,
+/// where every continuation line starts with a space.
+/// Lines are only split between characters, never within a multi-byte character.
+fn fold_line(line: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut current = String::new();
+    for c in line.chars() {
+        if current.len() + c.len_utf8() > MAX_LINE_OCTETS {
+            lines.push(std::mem::replace(&mut current, " ".into()));
+        }
+        current.push(c);
     }
+    lines.push(current);
+    lines
 }
 
 impl Display for RRuleSet {
     /// Prints a valid set of iCalendar properties which can be used to create a new [`RRuleSet`] later.
     /// You may use the generated string to create a new iCalendar component, like VEVENT.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let start_datetime = format!("DTSTART{}", datetime_to_ical_format(&self.dt_start));
+        let start_datetime = if self.dt_start_is_date {
+            format!("DTSTART{}", date_to_ical_format(&self.dt_start))
+        } else {
+            format!("DTSTART{}", datetime_to_ical_format(&self.dt_start))
+        };
 
         let mut rrules = self
             .rrule
             .iter()
-            .map(|rrule| format!("RRULE:{rrule}"))
+            .map(|rrule| format!("RRULE:{}", rule_to_string(rrule, self.dt_start_is_date)))
             .collect::<Vec<_>>()
             .join("\n");
         if !rrules.is_empty() {
@@ -310,7 +1438,7 @@ impl Display for RRuleSet {
         let mut exrules = self
             .exrule
             .iter()
-            .map(|exrule| format!("EXRULE:{exrule}"))
+            .map(|exrule| format!("EXRULE:{}", rule_to_string(exrule, self.dt_start_is_date)))
             .collect::<Vec<_>>()
             .join("\n");
         if !exrules.is_empty() {
@@ -332,6 +1460,18 @@ impl Display for RRuleSet {
     }
 }
 
+impl FromIterator<RRule> for RRuleSet {
+    /// Creates an [`RRuleSet`] with the given rrules.
+    ///
+    /// Validated rrules don't keep track of their start datetime,
+    /// so the start datetime of the set is the Unix epoch (`1970-01-01T00:00:00Z`).
+    /// Use [`RRuleSet::new`] and [`RRuleSet::set_rrules`] instead if the set needs a different start.
+    fn from_iter<T: IntoIterator<Item = RRule>>(iter: T) -> Self {
+        let epoch = chrono::DateTime::<chrono::Utc>::from(UNIX_EPOCH).with_timezone(&Tz::UTC);
+        Self::new(epoch).set_rrules(iter.into_iter().collect())
+    }
+}
+
 #[cfg(feature = "exrule")]
 #[cfg(test)]
 mod tests {
@@ -341,6 +1481,25 @@ mod tests {
 
     use crate::{Frequency, RRule, RRuleSet, Tz};
 
+    use super::{fold_line, MAX_LINE_OCTETS};
+
+    #[test]
+    fn folds_lines() {
+        assert_eq!(fold_line("RRULE:FREQ=DAILY"), vec!["RRULE:FREQ=DAILY"]);
+
+        let line = "X".repeat(200);
+        let folded = fold_line(&line);
+        assert_eq!(folded.len(), 3);
+        assert!(folded.iter().all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert_eq!(folded.join("\r\n").replace("\r\n ", ""), line);
+
+        // A multi-byte character is never split
+        let line = format!("{}ö{}", "X".repeat(74), "X".repeat(10));
+        let folded = fold_line(&line);
+        assert_eq!(folded[0], "X".repeat(74));
+        assert_eq!(folded[1], format!(" ö{}", "X".repeat(10)));
+    }
+
     #[test]
     fn rruleset_string_roundtrip() {
         let rruleset_str = "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=3;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nRDATE;VALUE=DATE-TIME:19970101T000000Z,19970120T000000Z\nEXRULE:FREQ=YEARLY;COUNT=8;BYMONTH=6,7;BYMONTHDAY=1;BYHOUR=9;BYMINUTE=30;BYSECOND=0\nEXDATE;VALUE=DATE-TIME:19970121T000000Z";
@@ -0,0 +1,112 @@
+use super::datetime::DateTime;
+use crate::{RRule, RRuleError, RRuleSet, Unvalidated};
+
+/// A builder for an [`RRuleSet`] that validates all its rrules and exrules at once.
+///
+/// Unlike adding validated rrules to an [`RRuleSet`] one by one, [`RRuleSetBuilder::build`]
+/// reports every invalid rrule and exrule instead of only the first one.
+///
+/// # Usage
+///
+/// ```
+/// use chrono::TimeZone;
+/// use rrule::{Frequency, RRule, RRuleSetBuilder, Tz};
+///
+/// let dt_start = Tz::UTC.with_ymd_and_hms(2021, 1, 1, 9, 0, 0).unwrap();
+/// let rrule_set = RRuleSetBuilder::new(dt_start)
+///     .rrule(RRule::new(Frequency::Daily).count(2))
+///     .rdate(Tz::UTC.with_ymd_and_hms(2021, 2, 1, 9, 0, 0).unwrap())
+///     .build()
+///     .unwrap();
+/// assert_eq!(rrule_set.all(10).dates.len(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RRuleSetBuilder {
+    dt_start: DateTime,
+    rrule: Vec<RRule<Unvalidated>>,
+    rdate: Vec<DateTime>,
+    #[cfg(feature = "exrule")]
+    exrule: Vec<RRule<Unvalidated>>,
+    exdate: Vec<DateTime>,
+}
+
+impl RRuleSetBuilder {
+    /// Creates an empty [`RRuleSetBuilder`], starting from `dt_start`.
+    #[must_use]
+    pub fn new(dt_start: DateTime) -> Self {
+        Self {
+            dt_start,
+            rrule: vec![],
+            rdate: vec![],
+            #[cfg(feature = "exrule")]
+            exrule: vec![],
+            exdate: vec![],
+        }
+    }
+
+    /// Adds a new unvalidated rrule to the builder.
+    #[must_use]
+    pub fn rrule(mut self, rrule: RRule<Unvalidated>) -> Self {
+        self.rrule.push(rrule);
+        self
+    }
+
+    /// Adds a new unvalidated exrule to the builder.
+    #[must_use]
+    #[cfg(feature = "exrule")]
+    pub fn exrule(mut self, exrule: RRule<Unvalidated>) -> Self {
+        self.exrule.push(exrule);
+        self
+    }
+
+    /// Adds a new rdate to the builder.
+    #[must_use]
+    pub fn rdate(mut self, rdate: DateTime) -> Self {
+        self.rdate.push(rdate);
+        self
+    }
+
+    /// Adds a new exdate to the builder.
+    #[must_use]
+    pub fn exdate(mut self, exdate: DateTime) -> Self {
+        self.exdate.push(exdate);
+        self
+    }
+
+    /// Validates all the rrules and exrules against the start datetime and creates an [`RRuleSet`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RRuleError::Multiple`] with the errors of every invalid rrule,
+    /// followed by the errors of every invalid exrule.
+    pub fn build(self) -> Result<RRuleSet, RRuleError> {
+        let dt_start = self.dt_start;
+        let mut errors = vec![];
+        let mut validate_all = |rrules: Vec<RRule<Unvalidated>>| {
+            rrules
+                .into_iter()
+                .filter_map(|rrule| {
+                    rrule
+                        .validate(dt_start)
+                        .map_err(|err| errors.push(err))
+                        .ok()
+                })
+                .collect::<Vec<_>>()
+        };
+        let rrules = validate_all(self.rrule);
+        #[cfg(feature = "exrule")]
+        let exrules = validate_all(self.exrule);
+
+        if !errors.is_empty() {
+            return Err(RRuleError::Multiple(errors));
+        }
+
+        let rrule_set = RRuleSet::new(dt_start)
+            .set_rrules(rrules)
+            .set_rdates(self.rdate)
+            .set_exdates(self.exdate);
+        #[cfg(feature = "exrule")]
+        let rrule_set = rrule_set.set_exrules(exrules);
+        Ok(rrule_set)
+    }
+}
@@ -0,0 +1,171 @@
+use super::datetime::DateTime;
+use crate::{Frequency, NWeekday, RRule, RRuleError, RRuleSet, Skip};
+use chrono::Weekday;
+#[cfg(feature = "serde")]
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A plain copy of the contents of an [`RRuleSet`], see [`RRuleSet::snapshot`].
+///
+/// Unlike [`RRuleSet`], all fields are public and the rules aren't generic over their
+/// validation stage, so the snapshot can be stored or compared field by field.
+/// Its string form is the same as the one of [`RRuleSet`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(DeserializeFromStr, SerializeDisplay))]
+pub struct RecurrenceSnapshot {
+    /// The start datetime of the recurring event.
+    pub dt_start: DateTime,
+    /// The rrules of the set.
+    pub rrules: Vec<RuleSnapshot>,
+    /// The exrules of the set.
+    pub exrules: Vec<RuleSnapshot>,
+    /// The rdates of the set.
+    pub rdates: Vec<DateTime>,
+    /// The exdates of the set.
+    pub exdates: Vec<DateTime>,
+}
+
+/// A plain copy of the rule parts of a validated [`RRule`].
+///
+/// The rule parts which validation filled in from `DTSTART`, like the `BYHOUR` of a daily
+/// rule, are included. Its string form is the same as the one of [`RRule`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(DeserializeFromStr, SerializeDisplay))]
+pub struct RuleSnapshot {
+    /// The frequency of the rule.
+    pub freq: Frequency,
+    /// The interval between each frequency iteration.
+    pub interval: u16,
+    /// The number of recurrences, if set.
+    pub count: Option<u32>,
+    /// The end of the recurrences, if set.
+    pub until: Option<DateTime>,
+    /// The week start day.
+    pub week_start: Weekday,
+    /// The `BYSETPOS` values.
+    pub by_set_pos: Vec<i32>,
+    /// The `BYMONTH` values.
+    pub by_month: Vec<u8>,
+    /// The `BYMONTHDAY` values, including the negative ones.
+    pub by_month_day: Vec<i8>,
+    /// The `BYYEARDAY` values.
+    pub by_year_day: Vec<i16>,
+    /// The `BYWEEKNO` values.
+    pub by_week_no: Vec<i8>,
+    /// The `BYDAY` values.
+    pub by_weekday: Vec<NWeekday>,
+    /// The `BYHOUR` values.
+    pub by_hour: Vec<u8>,
+    /// The `BYMINUTE` values.
+    pub by_minute: Vec<u8>,
+    /// The `BYSECOND` values.
+    pub by_second: Vec<u8>,
+    /// The `BYEASTER` value, if set.
+    pub by_easter: Option<i16>,
+    /// How invalid dates, like February 30th, are handled.
+    pub skip: Skip,
+}
+
+impl<S> From<&RRule<S>> for RuleSnapshot {
+    fn from(rrule: &RRule<S>) -> Self {
+        let mut by_month_day = rrule.by_month_day.clone();
+        by_month_day.extend(&rrule.by_n_month_day);
+        by_month_day.sort_unstable();
+
+        Self {
+            freq: rrule.freq,
+            interval: rrule.interval,
+            count: rrule.count,
+            until: rrule.until,
+            week_start: rrule.week_start,
+            by_set_pos: rrule.by_set_pos.clone(),
+            by_month: rrule.by_month.clone(),
+            by_month_day,
+            by_year_day: rrule.by_year_day.clone(),
+            by_week_no: rrule.by_week_no.clone(),
+            by_weekday: rrule.by_weekday.clone(),
+            by_hour: rrule.by_hour.clone(),
+            by_minute: rrule.by_minute.clone(),
+            by_second: rrule.by_second.clone(),
+            by_easter: rrule.by_easter,
+            skip: rrule.skip,
+        }
+    }
+}
+
+impl From<&RRuleSet> for RecurrenceSnapshot {
+    fn from(rrule_set: &RRuleSet) -> Self {
+        Self {
+            dt_start: rrule_set.dt_start,
+            rrules: rrule_set.rrule.iter().map(From::from).collect(),
+            exrules: rrule_set.exrule.iter().map(From::from).collect(),
+            rdates: rrule_set.rdate.clone(),
+            exdates: rrule_set.exdate.clone(),
+        }
+    }
+}
+
+impl RuleSnapshot {
+    /// Returns the rule with these rule parts, without validating it again.
+    fn to_rrule<S>(&self) -> RRule<S> {
+        let (by_n_month_day, by_month_day) = self.by_month_day.iter().partition(|day| **day < 0);
+
+        RRule {
+            freq: self.freq,
+            interval: self.interval,
+            count: self.count,
+            until: self.until,
+            week_start: self.week_start,
+            by_set_pos: self.by_set_pos.clone(),
+            by_month: self.by_month.clone(),
+            by_month_day,
+            by_n_month_day,
+            by_year_day: self.by_year_day.clone(),
+            by_week_no: self.by_week_no.clone(),
+            by_weekday: self.by_weekday.clone(),
+            by_hour: self.by_hour.clone(),
+            by_minute: self.by_minute.clone(),
+            by_second: self.by_second.clone(),
+            by_easter: self.by_easter,
+            skip: self.skip,
+            stage: PhantomData,
+        }
+    }
+}
+
+impl Display for RuleSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_rrule::<crate::Unvalidated>())
+    }
+}
+
+impl FromStr for RuleSnapshot {
+    type Err = RRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rrule: RRule<crate::Unvalidated> = s.parse()?;
+        Ok(Self::from(&rrule))
+    }
+}
+
+impl Display for RecurrenceSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The rules were validated before the snapshot was taken
+        let mut rrule_set = RRuleSet::new(self.dt_start);
+        rrule_set.rrule = self.rrules.iter().map(RuleSnapshot::to_rrule).collect();
+        rrule_set.exrule = self.exrules.iter().map(RuleSnapshot::to_rrule).collect();
+        rrule_set.rdate = self.rdates.clone();
+        rrule_set.exdate = self.exdates.clone();
+        write!(f, "{rrule_set}")
+    }
+}
+
+impl FromStr for RecurrenceSnapshot {
+    type Err = RRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<RRuleSet>()?.snapshot())
+    }
+}
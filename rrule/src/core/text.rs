@@ -0,0 +1,257 @@
+use super::datetime::DateTime;
+use crate::Frequency;
+use chrono::{Datelike, Weekday};
+
+/// Words and phrases used by [`RRule::to_text_with`](crate::RRule::to_text_with)
+/// to describe an rrule in a human language.
+///
+/// Every method has an English default, so an implementation only needs to override
+/// the methods it wants to change. [`English`] uses all the defaults.
+///
+/// # Usage
+///
+/// ```
+/// use chrono::Weekday;
+/// use rrule::{Frequency, Language, RRule, Unvalidated};
+///
+/// struct Dutch;
+///
+/// impl Language for Dutch {
+///     fn every(&self, freq: Frequency, interval: u16) -> String {
+///         match (freq, interval) {
+///             (Frequency::Weekly, 1) => "elke week".into(),
+///             (freq, interval) => rrule::English.every(freq, interval),
+///         }
+///     }
+///
+///     fn on_weekdays(&self, weekdays: &str) -> String {
+///         format!("op {weekdays}")
+///     }
+///
+///     fn weekday(&self, weekday: Weekday) -> String {
+///         match weekday {
+///             Weekday::Mon => "maandag".into(),
+///             weekday => rrule::English.weekday(weekday),
+///         }
+///     }
+/// }
+///
+/// let rrule: RRule<Unvalidated> = "FREQ=WEEKLY;BYDAY=MO".parse().unwrap();
+/// assert_eq!(rrule.to_text_with(&Dutch), "elke week op maandag");
+/// ```
+pub trait Language {
+    /// Name of a weekday, e.g. `Monday`.
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+            Weekday::Sun => "Sunday",
+        }
+        .into()
+    }
+
+    /// Name of a month from 1 to 12, e.g. `January`.
+    fn month(&self, month: u8) -> String {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        MONTHS
+            .get(usize::from(month).wrapping_sub(1))
+            .map_or_else(|| month.to_string(), ToString::to_string)
+    }
+
+    /// Ordinal of a number, where negative numbers count from the end, e.g. `2nd` or `last`.
+    fn ordinal(&self, n: i16) -> String {
+        if n == -1 {
+            return "last".into();
+        }
+        let abs = n.unsigned_abs();
+        let suffix = match (abs % 10, abs % 100) {
+            (1, 11) | (2, 12) | (3, 13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        if n < 0 {
+            format!("{abs}{suffix} last")
+        } else {
+            format!("{abs}{suffix}")
+        }
+    }
+
+    /// An nth weekday, e.g. `2nd Monday` or `last Friday`.
+    fn nth_weekday(&self, n: i16, weekday: Weekday) -> String {
+        format!("{} {}", self.ordinal(n), self.weekday(weekday))
+    }
+
+    /// Joins a list of items, e.g. `Monday, Tuesday and Friday`.
+    fn list(&self, items: &[String]) -> String {
+        match items {
+            [] => String::new(),
+            [item] => item.clone(),
+            [init @ .., last] => format!("{} and {}", init.join(", "), last),
+        }
+    }
+
+    /// The frequency with its interval, e.g. `every week` or `every 2 weeks`.
+    fn every(&self, freq: Frequency, interval: u16) -> String {
+        let unit = match freq {
+            Frequency::Yearly => "year",
+            Frequency::Monthly => "month",
+            Frequency::Weekly => "week",
+            Frequency::Daily => "day",
+            Frequency::Hourly => "hour",
+            Frequency::Minutely => "minute",
+            Frequency::Secondly => "second",
+        };
+        if interval == 1 {
+            format!("every {unit}")
+        } else {
+            format!("every {interval} {unit}s")
+        }
+    }
+
+    /// The weekdays the rrule recurs on, already joined by [`Language::list`],
+    /// e.g. `on Monday and Friday`.
+    fn on_weekdays(&self, weekdays: &str) -> String {
+        format!("on {weekdays}")
+    }
+
+    /// The days of the month the rrule recurs on, already joined by [`Language::list`],
+    /// e.g. `on the 1st and 15th day`.
+    fn on_month_days(&self, month_days: &str) -> String {
+        format!("on the {month_days} day")
+    }
+
+    /// The months the rrule recurs in, already joined by [`Language::list`],
+    /// e.g. `in January and July`.
+    fn in_months(&self, months: &str) -> String {
+        format!("in {months}")
+    }
+
+    /// The number of recurrences, e.g. `for 3 times`.
+    fn count(&self, count: u32) -> String {
+        if count == 1 {
+            "once".into()
+        } else {
+            format!("for {count} times")
+        }
+    }
+
+    /// The end of the rrule, e.g. `until September 2, 1997`.
+    fn until(&self, until: &DateTime) -> String {
+        format!(
+            "until {} {}, {}",
+            self.month(u8::try_from(until.month()).unwrap_or_default()),
+            until.day(),
+            until.year()
+        )
+    }
+}
+
+/// The English [`Language`], used by [`RRule::to_text`](crate::RRule::to_text).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English;
+
+impl Language for English {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RRule, Unvalidated};
+
+    struct German;
+
+    impl Language for German {
+        fn weekday(&self, weekday: Weekday) -> String {
+            match weekday {
+                Weekday::Mon => "Montag",
+                Weekday::Tue => "Dienstag",
+                Weekday::Wed => "Mittwoch",
+                Weekday::Thu => "Donnerstag",
+                Weekday::Fri => "Freitag",
+                Weekday::Sat => "Samstag",
+                Weekday::Sun => "Sonntag",
+            }
+            .into()
+        }
+
+        fn list(&self, items: &[String]) -> String {
+            items.join(" und ")
+        }
+
+        fn every(&self, freq: Frequency, interval: u16) -> String {
+            match (freq, interval) {
+                (Frequency::Weekly, 1) => "jede Woche".into(),
+                (Frequency::Weekly, interval) => format!("alle {interval} Wochen"),
+                (freq, interval) => English.every(freq, interval),
+            }
+        }
+
+        fn on_weekdays(&self, weekdays: &str) -> String {
+            format!("am {weekdays}")
+        }
+    }
+
+    fn rrule(s: &str) -> RRule<Unvalidated> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn to_text_in_english() {
+        let tests = [
+            ("FREQ=DAILY", "every day"),
+            (
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR",
+                "every 2 weeks on Monday, Wednesday and Friday",
+            ),
+            (
+                "FREQ=MONTHLY;BYMONTHDAY=1,-1;COUNT=1",
+                "every month on the 1st and last day once",
+            ),
+            (
+                "FREQ=MONTHLY;BYDAY=-2SU,3TH",
+                "every month on 2nd last Sunday and 3rd Thursday",
+            ),
+            (
+                "FREQ=YEARLY;BYMONTH=1,7;BYMONTHDAY=11,22,23",
+                "every year in January and July on the 11th, 22nd and 23rd day",
+            ),
+            (
+                "FREQ=HOURLY;UNTIL=19970902T090000Z",
+                "every hour until September 2, 1997",
+            ),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(rrule(input).to_text(), expected);
+        }
+    }
+
+    #[test]
+    fn to_text_in_german() {
+        assert_eq!(
+            rrule("FREQ=WEEKLY;BYDAY=MO").to_text_with(&German),
+            "jede Woche am Montag"
+        );
+        assert_eq!(
+            rrule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR").to_text_with(&German),
+            "alle 2 Wochen am Montag und Freitag"
+        );
+    }
+}
@@ -11,7 +11,7 @@ use std::ops::{
 /// In the case where the iterator ended with errors, the error will be included,
 /// otherwise the second value of the return tuple will be `None`.
 pub(super) fn collect_with_error<T>(
-    mut iterator: T,
+    iterator: T,
     start: &Option<DateTime>,
     end: &Option<DateTime>,
     inclusive: bool,
@@ -21,6 +21,28 @@ where
     T: Iterator<Item = DateTime> + WasLimited,
 {
     let mut list = vec![];
+    let limited = collect_into_with_error(iterator, start, end, inclusive, limit, &mut list);
+    RRuleResult {
+        dates: list,
+        limited,
+    }
+}
+
+/// Like [`collect_with_error`], but adds the dates to `list`, which should be empty
+/// as `limit` applies to its length.
+///
+/// Returns `true` if the iteration was limited.
+pub(super) fn collect_into_with_error<T>(
+    mut iterator: T,
+    start: &Option<DateTime>,
+    end: &Option<DateTime>,
+    inclusive: bool,
+    limit: Option<u16>,
+    list: &mut Vec<DateTime>,
+) -> bool
+where
+    T: Iterator<Item = DateTime> + WasLimited,
+{
     let mut was_limited = false;
     // This loop should always end because `.next()` has build in limits
     // Once a limit is tripped it will break in the `None` case.
@@ -39,16 +61,15 @@ where
         }
     }
 
-    was_limited = was_limited || matches!(limit, Some(limit) if usize::from(limit) == list.len());
-
-    RRuleResult {
-        dates: list,
-        limited: was_limited,
-    }
+    was_limited || matches!(limit, Some(limit) if usize::from(limit) == list.len())
 }
 
 /// Checks if `date` is after `end`.
-fn has_reached_the_end(date: &DateTime, end: &Option<DateTime>, inclusive: bool) -> bool {
+pub(super) fn has_reached_the_end(
+    date: &DateTime,
+    end: &Option<DateTime>,
+    inclusive: bool,
+) -> bool {
     if inclusive {
         match end {
             Some(end) => !(..=end).contains(&date),
@@ -16,6 +16,26 @@ pub enum RRuleError {
     /// Iterator error
     #[error("RRule iterator error: {0}")]
     IterError(String),
+    /// The rrule set has no end, while a finite set was expected
+    #[error("RRule set is infinite, at least one RRULE has neither a COUNT nor an UNTIL.")]
+    InfiniteRule,
+    /// The recurrences didn't fit within the given limit
+    #[error("RRule set has more recurrences than the given limit.")]
+    LimitReached,
+    /// An RRULE or EXRULE has a `BYHOUR`, `BYMINUTE` or `BYSECOND` while `DTSTART` is a date
+    #[error("RRule has a BYHOUR, BYMINUTE or BYSECOND, but DTSTART is a date without a time.")]
+    TimeOnDateOnlyEvent,
+    /// Multiple errors, e.g. when validating several rrules at once
+    #[error("{} RRule errors: {}", .0.len(), join_errors(.0))]
+    Multiple(Vec<Self>),
+}
+
+fn join_errors(errors: &[RRuleError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 impl RRuleError {
@@ -23,4 +43,41 @@ impl RRuleError {
     pub fn new_iter_err<S: AsRef<str>>(msg: S) -> Self {
         Self::IterError(msg.as_ref().to_owned())
     }
+
+    /// Returns a stable, machine-readable code for the error.
+    ///
+    /// Parsing and validation errors return the code of the wrapped error.
+    /// Unlike the error message, the code will not change between releases,
+    /// so it can be used to show localized messages.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ParserError(err) => err.code(),
+            Self::ValidationError(err) => err.code(),
+            Self::IterError(_) => "iter_error",
+            Self::InfiniteRule => "infinite_rule",
+            Self::LimitReached => "limit_reached",
+            Self::TimeOnDateOnlyEvent => "time_on_date_only_event",
+            Self::Multiple(_) => "multiple",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_are_stable() {
+        assert_eq!(
+            RRuleError::from(ParseError::InvalidFrequency("DAIL".into())).code(),
+            "invalid_frequency"
+        );
+        assert_eq!(ParseError::MissingStartDate.code(), "missing_start_date");
+        assert_eq!(
+            RRuleError::from(ValidationError::TooBigInterval(10_000)).code(),
+            "too_big_interval"
+        );
+        assert_eq!(RRuleError::InfiniteRule.code(), "infinite_rule");
+    }
 }
@@ -64,19 +64,16 @@ impl DateTimeIter {
     }
 
     fn increment_monthly(&mut self, interval: u16) -> Result<(), RRuleError> {
+        // Can't overflow, as the month is at most `12 + u16::MAX`
         self.month += u32::from(interval);
         if self.month > 12 {
-            let mut year_div = u16::try_from(self.month).map_err(|_| {
+            let year_div = i32::try_from((self.month - 1) / 12).map_err(|_| {
                 RRuleError::new_iter_err(
                     "Encountered a too high new month. Please decrease the rrule interval.",
                 )
-            })? / 12;
-            self.month %= 12;
-            if self.month == 0 {
-                self.month = 12;
-                year_div -= 1;
-            }
-            self.year += i32::from(year_div);
+            })?;
+            self.month = (self.month - 1) % 12 + 1;
+            self.year += year_div;
             checks::check_year_range(self.year)?;
         }
         Ok(())
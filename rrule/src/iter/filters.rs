@@ -14,6 +14,25 @@ const FILTERS: [RRuleFilter; 7] = [
     &is_filtered_by_year_day,
 ];
 
+/// The filters which also apply to a date that was moved from an invalid date, see [`Skip`].
+/// The month and month day of the moved date aren't checked, as they were moved on purpose.
+///
+/// [`Skip`]: crate::Skip
+const MOVED_DATE_FILTERS: [RRuleFilter; 5] = [
+    &is_filtered_by_week_number,
+    &is_filtered_by_weekday,
+    &is_filtered_by_neg_weekday,
+    &is_filtered_by_easter,
+    &is_filtered_by_year_day,
+];
+
+pub(crate) fn is_moved_date_filtered(ii: &IterInfo, current_day: usize) -> bool {
+    let rrule = ii.rrule();
+    MOVED_DATE_FILTERS
+        .into_iter()
+        .any(|filter| filter(ii, current_day, rrule))
+}
+
 pub(crate) fn is_filtered(ii: &IterInfo, current_day: usize) -> bool {
     let rrule = ii.rrule();
     FILTERS
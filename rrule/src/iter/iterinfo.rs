@@ -3,7 +3,7 @@ use super::counter_date::DateTimeIter;
 use super::easter::easter;
 use super::{monthinfo::MonthInfo, yearinfo::YearInfo};
 use crate::core::{get_month, DateTime};
-use crate::{Frequency, NWeekday, RRule};
+use crate::{Frequency, NWeekday, RRule, Skip};
 use chrono::{Datelike, NaiveTime, TimeZone};
 
 #[derive(Debug, Clone)]
@@ -199,9 +199,81 @@ impl IterInfo {
         // Filter out days according to the RRule filters.
         dayset.retain(|day| !super::filters::is_filtered(self, *day));
 
+        // Only monthly and yearly rules can generate invalid dates
+        let months = match freq {
+            _ if self.rrule.skip == Skip::Omit => return dayset,
+            Frequency::Yearly => 1..=12,
+            Frequency::Monthly => month..=month,
+            _ => return dayset,
+        };
+        dayset.extend(
+            months
+                .flat_map(|month| self.moved_days(month))
+                .filter(|day| !super::filters::is_moved_date_filtered(self, *day))
+                .filter(|day| !self.is_generated_by_neighbour(freq, month, *day)),
+        );
+        dayset.sort_unstable();
+        dayset.dedup();
+
         dayset
     }
 
+    /// Returns the days that the invalid `BYMONTHDAY` days of the month are moved to,
+    /// according to [`Skip`].
+    fn moved_days(&self, month: u32) -> Vec<usize> {
+        if !self.rrule.by_month.is_empty()
+            && !u8::try_from(month).is_ok_and(|month| self.rrule.by_month.contains(&month))
+        {
+            return vec![];
+        }
+        let month = usize::try_from(month).expect("target arch should have at least 32 bits");
+        let start = usize::from(self.month_range()[month - 1]);
+        let end = usize::from(self.month_range()[month]);
+        let month_len = end - start;
+
+        let mut days = vec![];
+        // Days after the end of the month
+        if self
+            .rrule
+            .by_month_day
+            .iter()
+            .any(|day| usize::try_from(*day).is_ok_and(|day| day > month_len))
+        {
+            days.push(match self.rrule.skip {
+                Skip::Forward => end,
+                _ => end - 1,
+            });
+        }
+        // Days before the start of the month
+        if self
+            .rrule
+            .by_n_month_day
+            .iter()
+            .any(|day| usize::from(day.unsigned_abs()) > month_len)
+        {
+            days.push(match self.rrule.skip {
+                Skip::Forward => start,
+                // January has 31 days, so it's never the month here
+                _ => start - 1,
+            });
+        }
+        days
+    }
+
+    /// Returns `true` if a day that was moved out of `month` is generated anyway by the
+    /// iteration of the neighbouring month, so it mustn't be counted twice.
+    ///
+    /// Yearly rules generate all the months at once, so their duplicates are already removed.
+    fn is_generated_by_neighbour(&self, freq: Frequency, month: u32, day: usize) -> bool {
+        if freq != Frequency::Monthly || self.rrule.interval != 1 {
+            return false;
+        }
+        let month = usize::try_from(month).expect("target arch should have at least 32 bits");
+        let start = usize::from(self.month_range()[month - 1]);
+        let end = usize::from(self.month_range()[month]);
+        !(start..end).contains(&day) && !super::filters::is_filtered(self, day)
+    }
+
     /// Gets a timeset without checking if the hour, minute and second are valid, according
     /// to the `RRule`.
     ///
@@ -224,47 +296,42 @@ impl IterInfo {
     /// An empty set is returned if the hour, minute and second aren't valid,
     /// according to the `RRule`.
     pub fn get_timeset(&self, hour: u8, minute: u8, second: u8) -> Vec<NaiveTime> {
-        match self.rrule.freq {
-            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
-                let incorrect_hour = self.rrule.freq >= Frequency::Hourly
-                    && !self.rrule.by_hour.is_empty()
-                    && !self.rrule.by_hour.contains(&hour);
-                let incorrect_minute = self.rrule.freq >= Frequency::Minutely
-                    && !self.rrule.by_minute.is_empty()
-                    && !self.rrule.by_minute.contains(&minute);
-                let incorrect_second = self.rrule.freq >= Frequency::Secondly
-                    && !self.rrule.by_second.is_empty()
-                    && !self.rrule.by_second.contains(&second);
-                let date_is_not_a_candidate =
-                    incorrect_hour || incorrect_minute || incorrect_second;
-
-                // If date is not a potential candidate, then we return an empty timeset.
-                if date_is_not_a_candidate {
-                    return vec![];
-                }
-
-                self.get_timeset_unchecked(hour, minute, second)
+        if self.rrule.freq.is_sub_daily() {
+            let incorrect_hour =
+                !self.rrule.by_hour.is_empty() && !self.rrule.by_hour.contains(&hour);
+            let incorrect_minute = self.rrule.freq >= Frequency::Minutely
+                && !self.rrule.by_minute.is_empty()
+                && !self.rrule.by_minute.contains(&minute);
+            let incorrect_second = self.rrule.freq >= Frequency::Secondly
+                && !self.rrule.by_second.is_empty()
+                && !self.rrule.by_second.contains(&second);
+            let date_is_not_a_candidate = incorrect_hour || incorrect_minute || incorrect_second;
+
+            // If date is not a potential candidate, then we return an empty timeset.
+            if date_is_not_a_candidate {
+                return vec![];
             }
-            _ => {
-                let timeset = self
-                    .rrule
-                    .by_hour
-                    .iter()
-                    .flat_map(|hour| {
-                        self.rrule.by_minute.iter().flat_map(move |minute| {
-                            self.rrule.by_second.iter().filter_map(move |second| {
-                                NaiveTime::from_hms_opt(
-                                    u32::from(*hour),
-                                    u32::from(*minute),
-                                    u32::from(*second),
-                                )
-                            })
+
+            self.get_timeset_unchecked(hour, minute, second)
+        } else {
+            let timeset = self
+                .rrule
+                .by_hour
+                .iter()
+                .flat_map(|hour| {
+                    self.rrule.by_minute.iter().flat_map(move |minute| {
+                        self.rrule.by_second.iter().filter_map(move |second| {
+                            NaiveTime::from_hms_opt(
+                                u32::from(*hour),
+                                u32::from(*minute),
+                                u32::from(*second),
+                            )
                         })
                     })
-                    .collect();
+                })
+                .collect();
 
-                timeset
-            }
+            timeset
         }
     }
 
@@ -2,6 +2,12 @@ use super::utils::{add_time_to_date, date_from_ordinal, pymod};
 use crate::core::{DateTime, Tz};
 use chrono::NaiveTime;
 
+/// Selects the recurrences at the `BYSETPOS` positions of a single period.
+///
+/// The candidates of the period are all combinations of `dayset` and `timeset`. Both are
+/// in chronological order, the dayset because it is a filtered range of days and the timeset
+/// because the `BYxxx` values are sorted during validation. So the positions always refer to
+/// the chronological order of the candidates, no matter which `BYxxx` rule parts contributed.
 pub(crate) fn build_pos_list(
     by_set_pos: &[i32],
     dayset: &[usize],
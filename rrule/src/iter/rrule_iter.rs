@@ -1,11 +1,148 @@
 use super::counter_date::DateTimeIter;
 use super::utils::add_time_to_date;
-use super::{build_pos_list, utils::date_from_ordinal, IterInfo, MAX_ITER_LOOP};
-use crate::core::{get_hour, get_minute, get_second};
-use crate::{core::DateTime, Frequency, RRule};
-use chrono::NaiveTime;
+use super::{build_pos_list, checks, utils::date_from_ordinal, IterInfo, MAX_ITER_LOOP};
+use crate::core::{get_day, get_hour, get_minute, get_month, get_second};
+use crate::{core::DateTime, Frequency, NWeekday, RRule};
+use chrono::{Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime};
 use std::collections::VecDeque;
 
+/// Iteration state of a rule which only repeats `DTSTART`, so without BYxxx rule parts
+/// other than the ones filled in from `DTSTART` during validation, like `FREQ=DAILY`.
+///
+/// The next recurrence of such a rule is found by adding the interval to the local datetime
+/// of the previous one, instead of generating and filtering candidates for every period.
+#[derive(Debug, Clone)]
+pub(crate) struct SimpleIter {
+    step: SimpleStep,
+    /// Local datetime of the next recurrence, `None` once the dates are out of range.
+    next: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SimpleStep {
+    Seconds(i64),
+    Days(u64),
+    Months(u32),
+}
+
+impl SimpleIter {
+    fn new(rrule: &RRule, dt_start: &DateTime) -> Option<Self> {
+        let hour = get_hour(dt_start);
+        let minute = get_minute(dt_start);
+        let second = get_second(dt_start);
+        if !rrule.by_set_pos.is_empty()
+            || !rrule.by_year_day.is_empty()
+            || !rrule.by_week_no.is_empty()
+            || !rrule.by_n_month_day.is_empty()
+            || rrule.by_easter.is_some()
+        {
+            return None;
+        }
+
+        // Validation fills in the parts of the time that don't change with the frequency
+        let (by_hour, by_minute, by_second): (&[u8], &[u8], &[u8]) = match rrule.freq {
+            Frequency::Secondly => (&[], &[], &[]),
+            Frequency::Minutely => (&[], &[], &[second]),
+            Frequency::Hourly => (&[], &[minute], &[second]),
+            _ => (&[hour], &[minute], &[second]),
+        };
+        if rrule.by_hour != by_hour || rrule.by_minute != by_minute || rrule.by_second != by_second
+        {
+            return None;
+        }
+        let no_day_parts = rrule.by_month.is_empty()
+            && rrule.by_month_day.is_empty()
+            && rrule.by_weekday.is_empty();
+
+        let interval = rrule.interval;
+        let step = match rrule.freq {
+            Frequency::Secondly if no_day_parts => SimpleStep::Seconds(i64::from(interval)),
+            Frequency::Minutely if no_day_parts => SimpleStep::Seconds(60 * i64::from(interval)),
+            Frequency::Hourly if no_day_parts => SimpleStep::Seconds(3600 * i64::from(interval)),
+            Frequency::Daily if no_day_parts => SimpleStep::Days(u64::from(interval)),
+            Frequency::Weekly
+                if rrule.by_month.is_empty()
+                    && rrule.by_month_day.is_empty()
+                    && rrule.by_weekday == [NWeekday::Every(dt_start.weekday())] =>
+            {
+                SimpleStep::Days(7 * u64::from(interval))
+            }
+            // Every month has the days up to the 28th, so none of them has to be skipped
+            Frequency::Monthly
+                if get_day(dt_start) <= 28
+                    && rrule.by_month.is_empty()
+                    && rrule.by_weekday.is_empty()
+                    && rrule.by_month_day == [get_day(dt_start)] =>
+            {
+                SimpleStep::Months(u32::from(interval))
+            }
+            Frequency::Yearly
+                if get_day(dt_start) <= 28
+                    && rrule.by_month == [get_month(dt_start)]
+                    && rrule.by_weekday.is_empty()
+                    && rrule.by_month_day == [get_day(dt_start)] =>
+            {
+                SimpleStep::Months(12 * u32::from(interval))
+            }
+            _ => return None,
+        };
+
+        let time = NaiveTime::from_hms_opt(u32::from(hour), u32::from(minute), u32::from(second))?;
+        Some(Self {
+            step,
+            next: Some(dt_start.date_naive().and_time(time)),
+        })
+    }
+
+    /// Returns the local datetime of the next recurrence and advances to the one after it.
+    fn next_datetime(&mut self) -> Option<NaiveDateTime> {
+        let next = self.next?;
+        self.next = match self.step {
+            SimpleStep::Seconds(seconds) => next.checked_add_signed(Duration::seconds(seconds)),
+            SimpleStep::Days(days) => next.checked_add_days(Days::new(days)),
+            SimpleStep::Months(months) => next.checked_add_months(Months::new(months)),
+        }
+        .filter(|next| checks::check_year_range(next.year()).is_ok());
+        Some(next)
+    }
+
+    /// Skips whole steps at once, as long as the date of the next recurrence
+    /// stays on or before `date`.
+    fn skip_to(&mut self, date: NaiveDate) {
+        let Some(current) = self.next else {
+            return;
+        };
+        self.next = match self.step {
+            SimpleStep::Seconds(seconds) => {
+                let Some(target) = date.and_hms_opt(0, 0, 0) else {
+                    return;
+                };
+                let diff = (target - current).num_seconds();
+                if diff < 0 {
+                    return;
+                }
+                current.checked_add_signed(Duration::seconds(diff - diff % seconds))
+            }
+            SimpleStep::Days(days) => {
+                let Ok(diff) = u64::try_from((date - current.date()).num_days()) else {
+                    return;
+                };
+                current.checked_add_days(Days::new(diff - diff % days))
+            }
+            SimpleStep::Months(months) => {
+                // One month less, as the day of the month might be after the day of `date`
+                let diff = (date.year() - current.year()) * 12 + date.month0() as i32
+                    - current.month0() as i32
+                    - 1;
+                let Ok(diff) = u32::try_from(diff) else {
+                    return;
+                };
+                current.checked_add_months(Months::new(diff - diff % months))
+            }
+        };
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct RRuleIter {
     /// Date the iterator is currently at.
@@ -25,6 +162,8 @@ pub(crate) struct RRuleIter {
     pub(crate) limited: bool,
     /// If the iterator has been stopped by the iterator limits.
     pub(crate) was_limited: bool,
+    /// Set for rules without BYxxx rule parts, which can skip the candidate generation.
+    pub(crate) simple: Option<SimpleIter>,
 }
 
 impl RRuleIter {
@@ -36,6 +175,7 @@ impl RRuleIter {
         let second = get_second(dt_start);
         let timeset = ii.get_timeset(hour, minute, second);
         let count = ii.rrule().count;
+        let simple = SimpleIter::new(rrule, dt_start);
 
         RRuleIter {
             counter_date: dt_start.into(),
@@ -47,9 +187,37 @@ impl RRuleIter {
             count,
             limited,
             was_limited: false,
+            simple,
+        }
+    }
+
+    /// Skips the recurrences before `dt` without generating them, which is much faster
+    /// for a reference time far after `DTSTART`.
+    ///
+    /// Only rules without BYxxx rule parts are skipped, including sub-daily rules like
+    /// `FREQ=SECONDLY`, as their recurrences can be computed directly. Rules with a `COUNT`
+    /// aren't skipped either, because every recurrence has to be generated to know when
+    /// the count is reached.
+    pub(crate) fn skip_before(&mut self, dt: &DateTime) {
+        if self.count.is_some() || !self.buffer.is_empty() {
+            return;
+        }
+        let Some(simple) = &mut self.simple else {
+            return;
+        };
+        // The day before, so that a change of the UTC offset can't skip a recurrence after `dt`
+        let date = dt.with_timezone(&self.dt_start.timezone()).date_naive();
+        if let Some(date) = date.pred_opt() {
+            simple.skip_to(date);
         }
     }
 
+    /// Returns `true` if [`RRuleIter::skip_before`] can skip the recurrences without
+    /// generating them.
+    pub(crate) fn can_skip(&self) -> bool {
+        self.count.is_none() && self.simple.is_some()
+    }
+
     /// Attempts to add a date to the result. Returns `true` if we should
     /// terminate the iteration.
     fn try_add_datetime(
@@ -97,6 +265,10 @@ impl RRuleIter {
             return true;
         }
 
+        if self.simple.is_some() {
+            return self.generate_simple();
+        }
+
         let mut loop_counter: u32 = 0;
         // Loop until there is at least 1 item in the buffer.
         while self.buffer.is_empty() {
@@ -177,10 +349,7 @@ impl RRuleIter {
                 return true;
             }
 
-            if matches!(
-                rrule.freq,
-                Frequency::Hourly | Frequency::Minutely | Frequency::Secondly
-            ) {
+            if rrule.freq.is_sub_daily() {
                 let hour =
                     u8::try_from(self.counter_date.hour).expect("range 0-23 is covered by u8");
                 let minute =
@@ -196,6 +365,30 @@ impl RRuleIter {
         // Indicate that there might be more items on the next iteration.
         false
     }
+
+    /// Adds the next recurrence of a rule without BYxxx rule parts to the buffer.
+    /// Returns true if finished, no more items should/can be returned.
+    fn generate_simple(&mut self) -> bool {
+        let Some(simple) = &mut self.simple else {
+            return true;
+        };
+        let rrule = self.ii.rrule();
+        let tz = self.dt_start.timezone();
+
+        while let Some(next) = simple.next_datetime() {
+            let Some(dt) = add_time_to_date(tz, next.date(), next.time()) else {
+                continue;
+            };
+            if Self::try_add_datetime(dt, rrule, &mut self.count, &mut self.buffer, &self.dt_start)
+            {
+                return true;
+            }
+            if !self.buffer.is_empty() {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 impl Iterator for RRuleIter {
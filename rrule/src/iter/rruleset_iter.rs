@@ -4,19 +4,30 @@ use crate::RRuleError;
 use crate::{core::DateTime, RRuleSet};
 use std::collections::BTreeSet;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::{collections::HashMap, iter::Iterator};
 
 #[derive(Debug, Clone)]
 /// Iterator over all the dates in an [`RRuleSet`].
+///
+/// Dates are returned in strictly increasing order, so without duplicates.
 pub struct RRuleSetIter {
     queue: HashMap<usize, DateTime>,
     limited: bool,
     rrule_iters: Vec<RRuleIter>,
+    /// The remaining `COUNT` of each rrule, when excluded dates don't count towards it.
+    remaining_counts: Vec<Option<u32>>,
     exrules: Vec<RRuleIter>,
     exdates: BTreeSet<i64>,
     /// Sorted additional dates in descending order
     rdates: Vec<DateTime>,
+    /// The last date returned by the iterator, used to skip duplicate dates.
+    last_date: Option<DateTime>,
+    /// The next date, if it was already generated by [`RRuleSetIter::peek`].
+    peeked: Option<Option<DateTime>>,
     was_limited: bool,
+    /// The set that is iterated over, used by [`RRuleSetIter::reset`].
+    rrule_set: Arc<RRuleSet>,
 }
 
 impl RRuleSetIter {
@@ -104,12 +115,10 @@ impl RRuleSetIter {
 
         exdates.contains(&date.timestamp())
     }
-}
-
-impl Iterator for RRuleSetIter {
-    type Item = DateTime;
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Returns the earliest date of all the rrules and rdates, which might be
+    /// equal to the previously returned date.
+    fn next_date(&mut self) -> Option<DateTime> {
         let mut next_date: Option<(usize, DateTime)> = None;
 
         // If there already was an error, return the error again.
@@ -121,6 +130,8 @@ impl Iterator for RRuleSetIter {
             let rrule_queue = self.queue.remove(&i);
             let next_rrule_date = if let Some(d) = rrule_queue {
                 Some(d)
+            } else if self.remaining_counts[i] == Some(0) {
+                None
             } else {
                 // should be method on self
                 let (date, was_limited) = Self::generate(
@@ -135,6 +146,12 @@ impl Iterator for RRuleSetIter {
                     return None;
                 }
 
+                if date.is_some() {
+                    if let Some(count) = &mut self.remaining_counts[i] {
+                        *count -= 1;
+                    }
+                }
+
                 date
             };
 
@@ -193,35 +210,129 @@ impl Iterator for RRuleSetIter {
     }
 }
 
+impl RRuleSetIter {
+    /// Returns the next date without advancing the iterator.
+    ///
+    /// Like [`std::iter::Peekable::peek`], the next date is generated
+    /// and kept until it is returned by [`Iterator::next`].
+    pub fn peek(&mut self) -> Option<&DateTime> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.next_unique_date());
+        }
+        self.peeked.as_ref().and_then(Option::as_ref)
+    }
+
+    /// Skips the recurrences of the rrules and exrules before `dt` where that is possible
+    /// without generating them, see [`RRuleIter::skip_before`].
+    ///
+    /// Afterwards, dates before `dt` might be missing or not excluded, so they must be ignored.
+    pub(crate) fn skip_before(&mut self, dt: &DateTime) {
+        for (i, rrule_iter) in self.rrule_iters.iter_mut().enumerate() {
+            if self.remaining_counts[i].is_none() && !self.queue.contains_key(&i) {
+                rrule_iter.skip_before(dt);
+            }
+        }
+        for exrule in &mut self.exrules {
+            exrule.skip_before(dt);
+        }
+    }
+
+    /// Restarts the iteration, so that the next date is the first occurrence again.
+    ///
+    /// The iterator keeps a shared reference to the validated [`RRuleSet`], so the set
+    /// doesn't have to be parsed or validated again.
+    pub fn reset(&mut self) {
+        *self = Self::new(Arc::clone(&self.rrule_set), self.limited);
+    }
+
+    /// Returns the next date that is different from the previously returned date.
+    fn next_unique_date(&mut self) -> Option<DateTime> {
+        loop {
+            let date = self.next_date()?;
+            // The same date can be generated by multiple rrules and rdates,
+            // but it should only be returned once.
+            if self.last_date != Some(date) {
+                debug_assert!(
+                    self.last_date.map_or(true, |last_date| last_date < date),
+                    "dates should be returned in increasing order, but `{date}` came after `{last_date:?}`",
+                    last_date = self.last_date,
+                );
+                self.last_date = Some(date);
+                return Some(date);
+            }
+        }
+    }
+}
+
+impl Iterator for RRuleSetIter {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.next_unique_date(),
+        }
+    }
+}
+
 impl IntoIterator for &RRuleSet {
     type Item = DateTime;
 
     type IntoIter = RRuleSetIter;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.iter_with_ctx(self.limited)
+    }
+}
+
+impl RRuleSet {
+    /// Returns an iterator over the set, which uses the iterator limits if `limited` is set.
+    pub(crate) fn iter_with_ctx(&self, limited: bool) -> RRuleSetIter {
+        RRuleSetIter::new(Arc::new(self.clone()), limited)
+    }
+}
+
+impl RRuleSetIter {
+    fn new(rrule_set: Arc<RRuleSet>, limited: bool) -> Self {
         // Sort in decreasing order
-        let mut rdates_sorted = self.rdate.clone();
+        let mut rdates_sorted = rrule_set.rdate.clone();
         rdates_sorted
             .sort_by(|d1, d2| d2.partial_cmp(d1).expect("Could not order dates correctly"));
 
-        let limited = self.limited;
+        // When excluded dates don't count towards `COUNT`, the rrules are iterated without
+        // a count, and the count is applied to the dates that are not excluded instead.
+        let (rrule_iters, remaining_counts): (Vec<_>, Vec<_>) = rrule_set
+            .rrule
+            .iter()
+            .map(|rrule| {
+                if rrule_set.count_includes_excluded {
+                    (rrule.iter_with_ctx(rrule_set.dt_start, limited), None)
+                } else {
+                    let mut rrule = rrule.clone();
+                    let count = rrule.count.take();
+                    (rrule.iter_with_ctx(rrule_set.dt_start, limited), count)
+                }
+            })
+            .unzip();
+
+        let exrules: Vec<RRuleIter> = rrule_set
+            .exrule
+            .iter()
+            .map(|exrule| exrule.iter_with_ctx(rrule_set.dt_start, limited))
+            .collect();
 
-        RRuleSetIter {
+        Self {
             queue: HashMap::new(),
             limited,
-            rrule_iters: self
-                .rrule
-                .iter()
-                .map(|rrule| rrule.iter_with_ctx(self.dt_start, limited))
-                .collect(),
+            rrule_iters,
+            remaining_counts,
             rdates: rdates_sorted,
-            exrules: self
-                .exrule
-                .iter()
-                .map(|exrule| exrule.iter_with_ctx(self.dt_start, limited))
-                .collect(),
-            exdates: self.exdate.iter().map(DateTime::timestamp).collect(),
+            exrules,
+            exdates: rrule_set.exdate.iter().map(DateTime::timestamp).collect(),
+            last_date: None,
+            peeked: None,
             was_limited: false,
+            rrule_set,
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::ops;
 
 use crate::core::{duration_from_midnight, DateTime, Tz};
-use chrono::{NaiveDate, NaiveTime, Utc};
+use chrono::{LocalResult, NaiveDate, NaiveTime, Utc};
 
 const DAY_SECS: i64 = 24 * 60 * 60;
 
@@ -72,12 +72,19 @@ where
     }
 }
 
+/// Returns the datetime at the local `time` on `date` in `tz`.
+///
+/// A time which occurs twice, because the clocks go back, is the first one, before
+/// the transition. A time which doesn't exist, because the clocks go forward, is moved
+/// forward by the length of the gap.
 pub(crate) fn add_time_to_date(tz: Tz, date: NaiveDate, time: NaiveTime) -> Option<DateTime> {
-    if let Some(dt) = date.and_time(time).and_local_timezone(tz).single() {
-        return Some(dt);
+    match date.and_time(time).and_local_timezone(tz) {
+        LocalResult::Single(dt) => return Some(dt),
+        LocalResult::Ambiguous(earliest, _) => return Some(earliest),
+        LocalResult::None => {}
     }
-    // If the day is a daylight saving time, the above code might not work, and we
-    // can try to get a valid datetime by adding the `time` as a duration instead.
+    // The time doesn't exist, so we get a valid datetime by adding the `time` as
+    // a duration to the start of the day instead.
     let dt = date.and_hms_opt(0, 0, 0)?.and_local_timezone(tz).single()?;
     let day_duration = duration_from_midnight(time);
     dt.checked_add_signed(day_duration)
@@ -100,7 +100,10 @@ mod parser;
 mod tests;
 mod validator;
 
-pub use crate::core::{Frequency, NWeekday, RRule, RRuleResult, RRuleSet, Tz};
+pub use crate::core::{
+    English, Event, Explanation, Frequency, Language, NWeekday, RRule, RRuleResult, RRuleSet,
+    RRuleSetBuilder, RecurrenceSnapshot, RuleSnapshot, Skip, Tz,
+};
 pub use crate::core::{Unvalidated, Validated};
 pub use chrono::Weekday;
 pub use error::{ParseError, RRuleError, ValidationError};
@@ -136,4 +136,37 @@ mod tests {
             assert_eq!(output, Ok(expected_output));
         }
     }
+
+    #[test]
+    fn rejects_date_in_dst_transition() {
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RDate,
+            parameters: Some("TZID=Europe/Berlin"),
+            value: "20210327T023000,20210328T023000",
+        };
+        let output: Result<Vec<DateTime>, _> = TryFrom::try_from(content_line);
+        assert_eq!(
+            output,
+            Err(ParseError::InvalidDateTimeInLocalTimezone {
+                value: "20210328T023000".into(),
+                property: "RDATE".into(),
+            })
+        );
+
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RDate,
+            parameters: Some("TZID=Europe/Berlin"),
+            value: "20211031T023000",
+        };
+        let output: Result<Vec<DateTime>, _> = TryFrom::try_from(content_line);
+        assert_eq!(
+            output,
+            Err(ParseError::DateTimeInLocalTimezoneIsAmbiguous {
+                value: "20211031T023000".into(),
+                property: "RDATE".into(),
+                date1: "2021-10-31T02:30:00+02:00".into(),
+                date2: "2021-10-31T02:30:00+01:00".into(),
+            })
+        );
+    }
 }
@@ -8,9 +8,9 @@ use crate::{
         datetime::{datestring_to_date, parse_weekdays},
         str_to_weekday,
         utils::parse_str_to_vec,
-        ParseError,
+        ParseContext, ParseError,
     },
-    Frequency, RRule, Unvalidated,
+    Frequency, RRule, Skip, Unvalidated,
 };
 
 use super::content_line_parts::ContentLineCaptures;
@@ -31,6 +31,8 @@ pub enum RRuleProperty {
     ByMonth,
     BySetPos,
     Wkst,
+    RScale,
+    Skip,
     #[cfg(feature = "by-easter")]
     ByEaster,
 }
@@ -54,6 +56,8 @@ impl FromStr for RRuleProperty {
             "BYMONTH" => Self::ByMonth,
             "BYSETPOS" => Self::BySetPos,
             "WKST" => Self::Wkst,
+            "RSCALE" => Self::RScale,
+            "SKIP" => Self::Skip,
             #[cfg(feature = "by-easter")]
             "BYEASTER" => Self::ByEaster,
             _ => return Err(ParseError::UnrecognizedParameter(s.into())),
@@ -66,6 +70,15 @@ impl<'a> TryFrom<ContentLineCaptures<'a>> for RRule<Unvalidated> {
     type Error = ParseError;
 
     fn try_from(value: ContentLineCaptures) -> Result<Self, Self::Error> {
+        Self::parse(&value, &mut ParseContext::default())
+    }
+}
+
+impl RRule<Unvalidated> {
+    pub(crate) fn parse(
+        value: &ContentLineCaptures,
+        ctx: &mut ParseContext,
+    ) -> Result<Self, ParseError> {
         if let Some(parameters) = value.parameters {
             if !parameters.is_empty() {
                 return Err(ParseError::PropertyParametersNotSupported(
@@ -74,17 +87,140 @@ impl<'a> TryFrom<ContentLineCaptures<'a>> for RRule<Unvalidated> {
             }
         }
 
-        let properties: HashMap<RRuleProperty, String> = parse_parameters(value.value)?;
+        reject_until_with_tzid(value.value)?;
 
-        props_to_rrule(&properties)
+        let properties: HashMap<RRuleProperty, String> = if ctx.lenient {
+            parse_parameters(&clean_lenient_parts(value.value, ctx))?
+        } else {
+            parse_parameters(value.value)?
+        };
+
+        let rrule = props_to_rrule(&properties)?;
+        // Some generators use `COUNT=0` for a rule without recurrences
+        if rrule.count == Some(0) {
+            let count = properties
+                .get(&RRuleProperty::Count)
+                .cloned()
+                .unwrap_or_default();
+            if !ctx.lenient {
+                return Err(ParseError::InvalidCount(count));
+            }
+            ctx.warn(format!(
+                "Accepted `COUNT={count}`, the rule has no recurrences."
+            ));
+        }
+        Ok(rrule)
     }
 }
 
+/// `UNTIL` is either in the timezone of `DTSTART` or in UTC, so it can't have a `TZID`,
+/// like in `UNTIL;TZID=Europe/Berlin:20200101T000000` or `UNTIL=TZID=Europe/Berlin:20200101T000000`.
+fn reject_until_with_tzid(value: &str) -> Result<(), ParseError> {
+    let is_tzid = |part: &str| {
+        part.get(..5)
+            .is_some_and(|name| name.eq_ignore_ascii_case("TZID="))
+    };
+    let parts = value.split(';').collect::<Vec<_>>();
+    for (idx, part) in parts.iter().enumerate() {
+        let until_with_tzid = match part.split_once('=') {
+            Some((name, value)) => name.eq_ignore_ascii_case("UNTIL") && is_tzid(value),
+            None => {
+                part.eq_ignore_ascii_case("UNTIL")
+                    && parts.get(idx + 1).is_some_and(|next| is_tzid(next))
+            }
+        };
+        if until_with_tzid {
+            return Err(ParseError::UntilWithTzid);
+        }
+    }
+    Ok(())
+}
+
+/// Cleans up the rule parts for lenient parsing, adding a warning for each change:
+/// - Rule parts separated by whitespace instead of `;`, like `FREQ=DAILY COUNT=3`, are split.
+/// - Whitespace around names and values is removed, like in `FREQ=WEEKLY `.
+/// - Rule parts with an unknown name, like vendor specific `X-` parts, are dropped.
+fn clean_lenient_parts(value: &str, ctx: &mut ParseContext) -> String {
+    let mut parts = vec![];
+    for part in value.split(';') {
+        let split_parts = split_at_whitespace(part);
+        if split_parts.len() > 1 {
+            ctx.warn(format!("Split rule parts `{part}` at whitespace."));
+            parts.extend(split_parts);
+        } else {
+            parts.push(part.to_string());
+        }
+    }
+
+    parts
+        .iter()
+        .filter_map(|part| {
+            let trimmed = match part.split_once('=') {
+                Some((name, value)) => format!("{}={}", name.trim(), value.trim()),
+                None => part.trim().to_string(),
+            };
+            if trimmed != *part {
+                ctx.warn(format!("Removed whitespace from rule part `{part}`."));
+            }
+            let name = trimmed
+                .split_once('=')
+                .map_or(&trimmed[..], |(name, _)| name);
+            if trimmed.is_empty() || RRuleProperty::from_str(name).is_ok() {
+                return Some(trimmed);
+            }
+            ctx.warn(format!("Ignoring unknown rule part `{trimmed}`."));
+            None
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Splits a rule part at whitespace which is followed by another rule part,
+/// like `FREQ=DAILY COUNT=3`. Whitespace around `=` or within a value is kept,
+/// like in `INTERVAL= 2`.
+fn split_at_whitespace(part: &str) -> Vec<String> {
+    let mut parts: Vec<String> = vec![];
+    for word in part.split_whitespace() {
+        match parts.last_mut() {
+            Some(last) if !starts_rule_part(last, word) => {
+                last.push(' ');
+                last.push_str(word);
+            }
+            _ => parts.push(word.to_string()),
+        }
+    }
+    parts
+}
+
+/// Returns `true` if `word` is the start of a new rule part, because it has a name
+/// and the previous rule part already has a value.
+fn starts_rule_part(previous: &str, word: &str) -> bool {
+    word.find('=').is_some_and(|idx| idx > 0)
+        && previous
+            .split_once('=')
+            .is_some_and(|(_, value)| !value.trim().is_empty())
+}
+
 /// Takes a map of [`RRuleProperty`] and returns an [`RRule`].
 #[allow(clippy::too_many_lines)]
 fn props_to_rrule(
     props: &HashMap<RRuleProperty, String>,
 ) -> Result<RRule<Unvalidated>, ParseError> {
+    // Only the Gregorian calendar (RFC 7529) is supported, which is also the default.
+    if let Some(rscale) = props.get(&RRuleProperty::RScale) {
+        if !rscale.eq_ignore_ascii_case("GREGORIAN") {
+            return Err(ParseError::UnsupportedCalendarScale(rscale.into()));
+        }
+    }
+    let skip = props
+        .get(&RRuleProperty::Skip)
+        .map(|skip| Skip::from_str(skip))
+        .transpose()?
+        .unwrap_or_default();
+    // RFC 7529 only allows `SKIP` together with `RSCALE`
+    if props.contains_key(&RRuleProperty::Skip) && !props.contains_key(&RRuleProperty::RScale) {
+        return Err(ParseError::SkipWithoutRScale);
+    }
     let freq = props
         .get(&RRuleProperty::Freq)
         .map(|freq| Frequency::from_str(freq))
@@ -218,6 +354,7 @@ fn props_to_rrule(
         by_minute,
         by_second,
         by_easter,
+        skip,
         stage: PhantomData,
     })
 }
@@ -225,6 +362,7 @@ fn props_to_rrule(
 #[cfg(test)]
 mod tests {
     use crate::parser::content_line::{ContentLineCaptures, PropertyName};
+    use crate::NWeekday;
 
     use super::*;
 
@@ -292,6 +430,159 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_week_start() {
+        let tests = [
+            ("FREQ=WEEKLY", Weekday::Mon),
+            ("FREQ=WEEKLY;WKST=MO", Weekday::Mon),
+            ("FREQ=WEEKLY;WKST=SU", Weekday::Sun),
+            ("FREQ=WEEKLY;WKST=we", Weekday::Wed),
+        ];
+
+        for (value, expected_week_start) in tests {
+            let input = ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value,
+            };
+            let output = RRule::try_from(input).unwrap();
+            assert_eq!(output.get_week_start(), expected_week_start);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_week_start() {
+        let mut props = HashMap::new();
+        props.insert(RRuleProperty::Freq, "WEEKLY".into());
+        props.insert(RRuleProperty::Wkst, "SUNDAY".into());
+        let res = props_to_rrule(&props);
+        assert_eq!(
+            res.unwrap_err(),
+            ParseError::InvalidWeekdayStart("SUNDAY".into())
+        );
+    }
+
+    #[test]
+    fn parses_gregorian_rscale() {
+        for value in [
+            "RSCALE=GREGORIAN;FREQ=MONTHLY",
+            "FREQ=MONTHLY;RSCALE=gregorian",
+        ] {
+            let input = ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value,
+            };
+            let output = RRule::try_from(input).unwrap();
+            assert_eq!(output, RRule::new(Frequency::Monthly));
+            // Gregorian is the default, so it isn't printed
+            assert_eq!(output.to_string(), "FREQ=MONTHLY");
+        }
+    }
+
+    #[test]
+    fn parses_rule_parts_in_any_order() {
+        for value in [
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=3;BYDAY=MO",
+            "INTERVAL=2;FREQ=WEEKLY;COUNT=3;BYDAY=MO",
+            "BYDAY=MO;COUNT=3;INTERVAL=2;FREQ=WEEKLY",
+        ] {
+            let content_line = ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value,
+            };
+            assert_eq!(
+                RRule::try_from(content_line),
+                Ok(RRule {
+                    freq: Frequency::Weekly,
+                    interval: 2,
+                    count: Some(3),
+                    by_weekday: vec![NWeekday::Every(Weekday::Mon)],
+                    ..Default::default()
+                }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_skip() {
+        for (value, skip) in [
+            ("RSCALE=GREGORIAN;FREQ=MONTHLY", Skip::Omit),
+            ("RSCALE=GREGORIAN;FREQ=MONTHLY;SKIP=OMIT", Skip::Omit),
+            (
+                "RSCALE=GREGORIAN;FREQ=MONTHLY;SKIP=backward",
+                Skip::Backward,
+            ),
+            ("FREQ=MONTHLY;SKIP=FORWARD;RSCALE=GREGORIAN", Skip::Forward),
+        ] {
+            let content_line = ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value,
+            };
+            assert_eq!(
+                RRule::try_from(content_line),
+                Ok(RRule {
+                    freq: Frequency::Monthly,
+                    skip,
+                    ..Default::default()
+                })
+            );
+        }
+
+        for (value, err) in [
+            ("FREQ=MONTHLY;SKIP=FORWARD", ParseError::SkipWithoutRScale),
+            (
+                "RSCALE=GREGORIAN;FREQ=MONTHLY;SKIP=SIDEWAYS",
+                ParseError::InvalidSkip("SIDEWAYS".into()),
+            ),
+        ] {
+            let content_line = ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value,
+            };
+            assert_eq!(RRule::try_from(content_line), Err(err));
+        }
+    }
+
+    #[test]
+    fn rejects_non_gregorian_rscale() {
+        let input = ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "RSCALE=HEBREW;FREQ=YEARLY;BYMONTH=5L",
+        };
+        let res = RRule::try_from(input);
+        assert_eq!(
+            res.unwrap_err(),
+            ParseError::UnsupportedCalendarScale("HEBREW".into())
+        );
+    }
+
+    #[test]
+    fn rejects_rrule_without_freq() {
+        let tests = [
+            ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value: "INTERVAL=2;BYDAY=MO",
+            },
+            ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value: "",
+            },
+        ];
+
+        for input in tests {
+            let output = RRule::try_from(input);
+            assert_eq!(output, Err(ParseError::MissingProperty("FREQ".into())));
+        }
+    }
+
     #[test]
     fn rejects_invalid_freq() {
         let mut props = HashMap::new();
@@ -331,4 +622,174 @@ mod tests {
             ParseError::InvalidByMinute("4,5,64".into())
         );
     }
+
+    #[test]
+    fn rejects_invalid_bysecond() {
+        let mut props = HashMap::new();
+        props.insert(RRuleProperty::Freq, "DAILY".into());
+        props.insert(RRuleProperty::BySecond, "61".into());
+        let res = props_to_rrule(&props);
+        assert_eq!(res.unwrap_err(), ParseError::InvalidBySecond("61".into()));
+    }
+
+    #[test]
+    fn rejects_negative_sub_day_parts() {
+        for (property, error) in [
+            (
+                RRuleProperty::ByHour,
+                ParseError::InvalidByHour("-1".into()),
+            ),
+            (
+                RRuleProperty::ByMinute,
+                ParseError::InvalidByMinute("-1".into()),
+            ),
+            (
+                RRuleProperty::BySecond,
+                ParseError::InvalidBySecond("-1".into()),
+            ),
+        ] {
+            let mut props = HashMap::new();
+            props.insert(RRuleProperty::Freq, "DAILY".into());
+            props.insert(property, "-1".into());
+            let err = props_to_rrule(&props).unwrap_err();
+            assert_eq!(err, error);
+            assert!(err.to_string().starts_with("`-1` is not a valid BY"));
+        }
+    }
+
+    #[test]
+    fn skips_unknown_parts_in_lenient_mode() {
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "FREQ=DAILY;X-CUSTOM=foo=bar;COUNT=3;X-EMPTY",
+        };
+
+        let mut ctx = ParseContext::lenient();
+        assert_eq!(
+            RRule::parse(&content_line, &mut ctx),
+            Ok(RRule {
+                freq: Frequency::Daily,
+                count: Some(3),
+                ..Default::default()
+            })
+        );
+        assert_eq!(ctx.warnings.len(), 2);
+
+        assert_eq!(
+            RRule::try_from(content_line),
+            Err(ParseError::UnrecognizedParameter("X-CUSTOM".into()))
+        );
+    }
+
+    #[test]
+    fn parses_zero_count_in_lenient_mode() {
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "FREQ=DAILY;COUNT=0",
+        };
+
+        let mut ctx = ParseContext::lenient();
+        assert_eq!(
+            RRule::parse(&content_line, &mut ctx),
+            Ok(RRule {
+                freq: Frequency::Daily,
+                count: Some(0),
+                ..Default::default()
+            })
+        );
+        assert_eq!(ctx.warnings.len(), 1);
+
+        assert_eq!(
+            RRule::try_from(content_line),
+            Err(ParseError::InvalidCount("0".into()))
+        );
+    }
+
+    #[test]
+    fn splits_parts_at_whitespace_in_lenient_mode() {
+        let expected = RRule::try_from(ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "FREQ=WEEKLY;INTERVAL=2;COUNT=3;BYDAY=MO,TU",
+        });
+        assert!(expected.is_ok());
+
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "FREQ=WEEKLY INTERVAL=2  COUNT=3;BYDAY=MO,TU",
+        };
+        let mut ctx = ParseContext::lenient();
+        assert_eq!(RRule::parse(&content_line, &mut ctx), expected);
+        assert_eq!(ctx.warnings.len(), 1);
+
+        assert!(RRule::try_from(content_line).is_err());
+    }
+
+    #[test]
+    fn trims_whitespace_in_lenient_mode() {
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "FREQ=WEEKLY ; INTERVAL= 2;COUNT=3",
+        };
+
+        let mut ctx = ParseContext::lenient();
+        assert_eq!(
+            RRule::parse(&content_line, &mut ctx),
+            Ok(RRule {
+                freq: Frequency::Weekly,
+                interval: 2,
+                count: Some(3),
+                ..Default::default()
+            })
+        );
+        assert_eq!(ctx.warnings.len(), 2);
+
+        let content_line = ContentLineCaptures {
+            property_name: PropertyName::RRule,
+            parameters: None,
+            value: "FREQ=WEEKLY ",
+        };
+        assert_eq!(
+            RRule::try_from(content_line),
+            Err(ParseError::InvalidFrequency("WEEKLY ".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_until_with_tzid() {
+        for value in [
+            "FREQ=DAILY;UNTIL;TZID=Europe/Berlin:20200101T000000",
+            "FREQ=DAILY;UNTIL=TZID=Europe/Berlin:20200101T000000",
+        ] {
+            let content_line = ContentLineCaptures {
+                property_name: PropertyName::RRule,
+                parameters: None,
+                value,
+            };
+            assert_eq!(
+                RRule::try_from(content_line),
+                Err(ParseError::UntilWithTzid)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_count() {
+        let mut props = HashMap::new();
+        props.insert(RRuleProperty::Freq, "DAILY".into());
+        props.insert(RRuleProperty::Count, "99999999999999".into());
+        let res = props_to_rrule(&props);
+        assert_eq!(
+            res.unwrap_err(),
+            ParseError::InvalidCount("99999999999999".into())
+        );
+
+        props.insert(RRuleProperty::Count, "-1".into());
+        let res = props_to_rrule(&props);
+        assert_eq!(res.unwrap_err(), ParseError::InvalidCount("-1".into()));
+    }
 }
@@ -8,9 +8,11 @@ use crate::{
     core::{DateTime, Tz},
     parser::{
         datetime::{datestring_to_date, parse_timezone},
-        ParseError,
+        regex::split_utc_offset,
+        ParseContext, ParseError,
     },
 };
+use chrono::TimeZone;
 
 const UTC: Tz = Tz::UTC;
 
@@ -25,6 +27,15 @@ impl<'a> TryFrom<&ContentLineCaptures<'a>> for StartDateContentLine {
     type Error = ParseError;
 
     fn try_from(content_line: &ContentLineCaptures) -> Result<Self, Self::Error> {
+        Self::parse(content_line, &mut ParseContext::default())
+    }
+}
+
+impl StartDateContentLine {
+    pub(crate) fn parse(
+        content_line: &ContentLineCaptures,
+        ctx: &mut ParseContext,
+    ) -> Result<Self, ParseError> {
         let parameters: HashMap<DateParameter, String> = content_line
             .parameters
             .as_ref()
@@ -32,16 +43,23 @@ impl<'a> TryFrom<&ContentLineCaptures<'a>> for StartDateContentLine {
             .transpose()?
             .unwrap_or_default();
 
+        // A trailing `;` is sometimes seen before the RRULE, like in RRULE parts,
+        // so it's ignored instead of being treated as part of the time.
+        let datetime_value = content_line
+            .value
+            .strip_suffix(';')
+            .unwrap_or(content_line.value);
+
         let mut timezone = parameters
             .get(&DateParameter::Timezone)
             .map(|tz| parse_timezone(tz))
             .transpose()?;
-        if timezone.is_none() && content_line.value.to_uppercase().ends_with('Z') {
+        if timezone.is_none() && datetime_value.to_uppercase().ends_with('Z') {
             timezone = Some(UTC);
         }
 
         let value_in_parameter = parameters.get(&DateParameter::Value);
-        let value = if content_line.value.len() > 8 {
+        let value = if datetime_value.len() > 8 {
             "DATE-TIME"
         } else {
             "DATE"
@@ -56,7 +74,31 @@ impl<'a> TryFrom<&ContentLineCaptures<'a>> for StartDateContentLine {
             }
         }
 
-        let datetime = datestring_to_date(content_line.value, timezone, "DTSTART")?;
+        // In lenient mode, accept a numeric UTC offset like `19970902T090000+0200`,
+        // which is not allowed by the RFC.
+        let utc_offset = if ctx.lenient && timezone.is_none() {
+            split_utc_offset(datetime_value)
+        } else {
+            None
+        };
+        let datetime = if let Some((value, offset)) = utc_offset {
+            ctx.warn(format!(
+                "`DTSTART` value `{}` has a numeric UTC offset, which is not allowed. It will be converted to UTC.",
+                datetime_value
+            ));
+            timezone = Some(UTC);
+            let datetime = datestring_to_date(value, Some(UTC), "DTSTART")?;
+            offset
+                .from_local_datetime(&datetime.naive_utc())
+                .single()
+                .ok_or_else(|| ParseError::InvalidDateTime {
+                    value: datetime_value.into(),
+                    property: "DTSTART".into(),
+                })?
+                .with_timezone(&UTC)
+        } else {
+            datestring_to_date(datetime_value, timezone, "DTSTART")?
+        };
 
         Ok(Self {
             datetime,
@@ -68,8 +110,6 @@ impl<'a> TryFrom<&ContentLineCaptures<'a>> for StartDateContentLine {
 
 #[cfg(test)]
 mod tests {
-    use chrono::TimeZone;
-
     use crate::parser::content_line::PropertyName;
 
     use super::*;
@@ -121,6 +161,128 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_dtstart_with_parameters_in_any_order() {
+        let berlin = Tz::Tz(chrono_tz::Tz::Europe__Berlin);
+        for parameters in [
+            "VALUE=DATE-TIME;TZID=Europe/Berlin",
+            "TZID=Europe/Berlin;VALUE=DATE-TIME",
+            "TZID=Europe/Berlin",
+        ] {
+            let content = ContentLineCaptures {
+                property_name: PropertyName::DtStart,
+                parameters: Some(parameters),
+                value: "19970902T090000",
+            };
+            assert_eq!(
+                StartDateContentLine::try_from(&content),
+                Ok(StartDateContentLine {
+                    datetime: berlin.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap(),
+                    timezone: Some(berlin),
+                    value: "DATE-TIME",
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parses_dtstart_with_trailing_semicolon() {
+        let content = ContentLineCaptures::new("DTSTART:19970902T090000Z;").unwrap();
+        assert_eq!(
+            StartDateContentLine::try_from(&content),
+            Ok(StartDateContentLine {
+                datetime: UTC.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap(),
+                timezone: Some(UTC),
+                value: "DATE-TIME",
+            })
+        );
+
+        let set: crate::RRuleSet = "DTSTART:19970902T090000Z;\nRRULE:FREQ=DAILY;COUNT=1"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            set.all(10).dates,
+            vec![UTC.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap()]
+        );
+    }
+
+    #[test]
+    fn parses_dtstart_with_multi_component_tzid() {
+        for (line, tz) in [
+            (
+                "DTSTART;TZID=America/Argentina/Buenos_Aires:19970902T090000",
+                chrono_tz::Tz::America__Argentina__Buenos_Aires,
+            ),
+            (
+                "DTSTART;TZID=America/Indiana/Indianapolis:19970902T090000",
+                chrono_tz::Tz::America__Indiana__Indianapolis,
+            ),
+        ] {
+            let tz = Tz::Tz(tz);
+            let content = ContentLineCaptures::new(line).unwrap();
+            assert_eq!(
+                StartDateContentLine::try_from(&content),
+                Ok(StartDateContentLine {
+                    datetime: tz.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap(),
+                    timezone: Some(tz),
+                    value: "DATE-TIME",
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parses_dtstart_with_utc_offset_in_lenient_mode() {
+        let tests = [
+            (
+                "19970902T090000+0200",
+                UTC.with_ymd_and_hms(1997, 9, 2, 7, 0, 0).unwrap(),
+            ),
+            (
+                "19970902T090000-0530",
+                UTC.with_ymd_and_hms(1997, 9, 2, 14, 30, 0).unwrap(),
+            ),
+            (
+                "19970902T090000Z",
+                UTC.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap(),
+            ),
+        ];
+
+        for (value, expected_datetime) in tests {
+            let content = ContentLineCaptures {
+                property_name: PropertyName::DtStart,
+                parameters: None,
+                value,
+            };
+            let output = StartDateContentLine::parse(&content, &mut ParseContext::lenient());
+            assert_eq!(
+                output,
+                Ok(StartDateContentLine {
+                    datetime: expected_datetime,
+                    timezone: Some(UTC),
+                    value: "DATE-TIME",
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_dtstart_with_utc_offset_in_strict_mode() {
+        let content = ContentLineCaptures {
+            property_name: PropertyName::DtStart,
+            parameters: None,
+            value: "19970902T090000+0200",
+        };
+        let output = StartDateContentLine::try_from(&content);
+        assert_eq!(
+            output,
+            Err(ParseError::InvalidDateTime {
+                value: "19970902T090000+0200".into(),
+                property: "DTSTART".into()
+            })
+        );
+    }
+
     #[test]
     fn rejects_invalid_dtstart() {
         let tests = [
@@ -165,7 +327,7 @@ mod tests {
         let err = res.unwrap_err();
         assert_eq!(
             err,
-            ParseError::InvalidTimezone("America/Everywhere".into())
+            ParseError::UnknownTimezone("America/Everywhere".into())
         );
     }
 
@@ -8,15 +8,49 @@ use crate::{
 use chrono::{NaiveDate, TimeZone, Weekday};
 
 /// Attempts to convert a `str` to a `chrono_tz::Tz`.
+///
+/// A name that looks like an IANA timezone (e.g. `Europe/Atlantis`), but is not part of the
+/// timezone database, results in [`ParseError::UnknownTimezone`] instead of
+/// [`ParseError::InvalidTimezone`].
+///
+/// With the `windows-timezones` feature, Windows timezone names like `Pacific Standard Time`
+/// are accepted as well.
 pub(crate) fn parse_timezone(tz: &str) -> Result<Tz, ParseError> {
-    chrono_tz::Tz::from_str(tz)
-        .map_err(|_| ParseError::InvalidTimezone(tz.into()))
+    let parsed = chrono_tz::Tz::from_str(tz);
+    #[cfg(feature = "windows-timezones")]
+    let parsed = parsed.or_else(|err| {
+        super::windows_timezones::windows_to_iana(tz).map_or(Err(err), chrono_tz::Tz::from_str)
+    });
+
+    parsed
+        .map_err(|_| {
+            if is_timezone_name(tz) {
+                ParseError::UnknownTimezone(tz.into())
+            } else {
+                ParseError::InvalidTimezone(tz.into())
+            }
+        })
         .map(Tz::Tz)
 }
 
+/// Checks if the `str` has the shape of an IANA timezone name,
+/// which is one or more `/` separated segments like `America/Argentina/Buenos_Aires`.
+fn is_timezone_name(tz: &str) -> bool {
+    tz.split('/').all(|segment| {
+        segment.starts_with(|c: char| c.is_ascii_alphabetic())
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    })
+}
+
 /// Convert a datetime string and a timezone to a `chrono::DateTime<Tz>`.
 /// If the string specifies a zulu timezone with `Z`, then the timezone
 /// argument will be ignored.
+///
+/// This is used for every datetime property (`DTSTART`, `UNTIL`, `RDATE` and `EXDATE`),
+/// so a local datetime which doesn't exist or is ambiguous because of a DST transition
+/// is rejected the same way for all of them.
 pub(crate) fn datestring_to_date(
     dt: &str,
     tz: Option<Tz>,
@@ -145,6 +179,45 @@ mod tests {
 
     const US_PACIFIC: Tz = Tz::US__Pacific;
 
+    #[test]
+    fn parses_timezone() {
+        assert_eq!(
+            parse_timezone("Europe/Berlin"),
+            Ok(Tz::Tz(chrono_tz::Tz::Europe__Berlin))
+        );
+        assert_eq!(
+            parse_timezone("America/Argentina/Buenos_Aires"),
+            Ok(Tz::Tz(chrono_tz::Tz::America__Argentina__Buenos_Aires))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_timezone() {
+        for tz in ["Europe/Atlantis", "Mars/Olympus_Mons", "Etc/GMT+15"] {
+            assert_eq!(
+                parse_timezone(tz),
+                Err(ParseError::UnknownTimezone(tz.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_timezone() {
+        for tz in [
+            "",
+            "Europe/",
+            "/Berlin",
+            "Europe Berlin",
+            "Europe/Berlin!",
+            "1/Berlin",
+        ] {
+            assert_eq!(
+                parse_timezone(tz),
+                Err(ParseError::InvalidTimezone(tz.into()))
+            );
+        }
+    }
+
     #[test]
     fn parses_valid_nweekdays() {
         let tests = [
@@ -241,6 +314,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rejects_datetime_in_dst_gap() {
+        let berlin = Tz::Europe__Berlin;
+        for property in ["DTSTART", "UNTIL", "RDATE"] {
+            assert_eq!(
+                datestring_to_date("20210328T023000", Some(berlin), property),
+                Err(ParseError::InvalidDateTimeInLocalTimezone {
+                    value: "20210328T023000".into(),
+                    property: property.into(),
+                })
+            );
+        }
+    }
+
     #[test]
     fn rejects_invalid_datetime_str() {
         let tests = [
@@ -5,6 +5,8 @@ use thiserror::Error;
 pub enum ParseError {
     #[error("`{0}` is not a valid timezone.")]
     InvalidTimezone(String),
+    #[error("`{0}` is not a known timezone. It might not be part of the timezone database this version of the library was built with.")]
+    UnknownTimezone(String),
     #[error("`{value}` is not a valid datetime format for `{property}`.")]
     InvalidDateTime { value: String, property: String },
     #[error("`{0}` is not a valid datetime format.")]
@@ -24,6 +26,12 @@ pub enum ParseError {
     InvalidWeekday(String),
     #[error("`{0}` is not a valid weekday start. Valid values are `MO`, `TU`, `WE`, `TH`, `FR`, `SA` and `SU`.")]
     InvalidWeekdayStart(String),
+    #[error("`{0}` is not a supported RSCALE value. Only `GREGORIAN` is supported.")]
+    UnsupportedCalendarScale(String),
+    #[error("`{0}` is not a valid SKIP value. Valid values are `OMIT`, `BACKWARD` and `FORWARD`.")]
+    InvalidSkip(String),
+    #[error("`SKIP` can only be used together with `RSCALE`.")]
+    SkipWithoutRScale,
     #[error("`{0}` is not a valid BYEASTER value.")]
     InvalidByEaster(String),
     #[error("`{0}` is not a valid INTERVAL value.")]
@@ -58,14 +66,20 @@ pub enum ParseError {
     DuplicateProperty(String),
     #[error("Missing start date. There needs to be a unique start date which the iteration can start from.")]
     MissingStartDate,
+    #[error("Found multiple start dates. There needs to be a unique start date which the iteration can start from.")]
+    MultipleDtStart,
     #[error("Missing date generation property. There needs to be at least one `RRULE` or `RDATE` to generate occurrences.")]
     MissingDateGenerationRules,
+    #[error("Expected exactly one `RRULE` and no `RDATE`, `EXRULE` or `EXDATE` properties.")]
+    ExpectedSingleRRule,
     #[error("Property parameters aren't supported for RRULE / EXRULE, found parameters: `{0}`")]
     PropertyParametersNotSupported(String),
     #[error(
         "`{0}` is not a valid property name, expected one of: `RRULE,EXRULE,DTSTART,RDATE,EXDATE`"
     )]
     UnrecognizedPropertyName(String),
+    #[error("`UNTIL` can't have a `TZID` parameter, it needs to be in the timezone of `DTSTART` or in UTC.")]
+    UntilWithTzid,
     #[error(
         "The value of `DTSTART` and `UNTIL` needs to match. Both need to be either a `DATE` or `DATE-TIME`"
     )]
@@ -81,3 +95,54 @@ pub enum ParseError {
         found_value: String,
     },
 }
+
+impl ParseError {
+    /// Returns a stable, machine-readable code for the error.
+    ///
+    /// Unlike the error message, the code will not change between releases,
+    /// so it can be used to show localized messages.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidTimezone(_) => "invalid_timezone",
+            Self::UnknownTimezone(_) => "unknown_timezone",
+            Self::InvalidDateTime { .. } => "invalid_date_time",
+            Self::InvalidDateTimeFormat(_) => "invalid_date_time_format",
+            Self::InvalidDateTimeInLocalTimezone { .. } => "invalid_date_time_in_local_timezone",
+            Self::DateTimeInLocalTimezoneIsAmbiguous { .. } => {
+                "date_time_in_local_timezone_is_ambiguous"
+            }
+            Self::InvalidFrequency(_) => "invalid_frequency",
+            Self::InvalidWeekday(_) => "invalid_weekday",
+            Self::InvalidWeekdayStart(_) => "invalid_weekday_start",
+            Self::UnsupportedCalendarScale(_) => "unsupported_calendar_scale",
+            Self::InvalidSkip(_) => "invalid_skip",
+            Self::SkipWithoutRScale => "skip_without_rscale",
+            Self::InvalidByEaster(_) => "invalid_by_easter",
+            Self::InvalidInterval(_) => "invalid_interval",
+            Self::InvalidCount(_) => "invalid_count",
+            Self::InvalidByHour(_) => "invalid_by_hour",
+            Self::InvalidByWeekNo(_) => "invalid_by_week_no",
+            Self::InvalidByYearDay(_) => "invalid_by_year_day",
+            Self::InvalidByMonthDay(_) => "invalid_by_month_day",
+            Self::InvalidByMonth(_) => "invalid_by_month",
+            Self::InvalidByMinute(_) => "invalid_by_minute",
+            Self::InvalidBySecond(_) => "invalid_by_second",
+            Self::InvalidBySetPos(_) => "invalid_by_set_pos",
+            Self::MissingProperty(_) => "missing_property",
+            Self::InvalidParameterFormat(_) => "invalid_parameter_format",
+            Self::UnrecognizedParameter(_) => "unrecognized_parameter",
+            Self::DuplicateProperty(_) => "duplicate_property",
+            Self::MissingStartDate => "missing_start_date",
+            Self::MultipleDtStart => "multiple_dt_start",
+            Self::MissingDateGenerationRules => "missing_date_generation_rules",
+            Self::ExpectedSingleRRule => "expected_single_rrule",
+            Self::PropertyParametersNotSupported(_) => "property_parameters_not_supported",
+            Self::UnrecognizedPropertyName(_) => "unrecognized_property_name",
+            Self::UntilWithTzid => "until_with_tzid",
+            Self::DtStartUntilMismatchValue => "dt_start_until_mismatch_value",
+            Self::DtStartUntilMismatchTimezone => "dt_start_until_mismatch_timezone",
+            Self::ParameterValueMismatch { .. } => "parameter_value_mismatch",
+        }
+    }
+}
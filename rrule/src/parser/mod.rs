@@ -5,7 +5,10 @@ mod datetime;
 mod error;
 mod regex;
 mod utils;
+#[cfg(feature = "windows-timezones")]
+mod windows_timezones;
 
+use std::borrow::Cow;
 use std::str::FromStr;
 
 pub(crate) use content_line::{ContentLine, ContentLineCaptures};
@@ -16,6 +19,32 @@ use crate::RRule;
 
 use self::content_line::{PropertyName, StartDateContentLine};
 
+/// Parser state shared while parsing a single input.
+#[derive(Debug, Default)]
+pub(crate) struct ParseContext {
+    /// If set, some commonly seen input that doesn't follow the RFC is accepted
+    /// instead of being rejected.
+    pub lenient: bool,
+    /// Non-conforming input that was accepted in lenient mode.
+    pub warnings: Vec<String>,
+}
+
+impl ParseContext {
+    pub fn lenient() -> Self {
+        Self {
+            lenient: true,
+            warnings: vec![],
+        }
+    }
+
+    /// Logs the warning and keeps it, so it can be returned to the caller.
+    pub fn warn<S: Into<String>>(&mut self, warning: S) {
+        let warning = warning.into();
+        log::warn!("{}", warning);
+        self.warnings.push(warning);
+    }
+}
+
 /// Grammar represents a well-formatted rrule input.
 #[derive(Debug, PartialEq)]
 pub(crate) struct Grammar {
@@ -27,27 +56,54 @@ impl FromStr for Grammar {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let content_lines_parts = s
-            .lines()
-            .map(ContentLineCaptures::new)
+        Self::parse(s, &mut ParseContext::default())
+    }
+}
+
+impl Grammar {
+    pub(crate) fn parse(s: &str, ctx: &mut ParseContext) -> Result<Self, ParseError> {
+        let mut lines = vec![];
+        for line in s.lines() {
+            if !ctx.lenient {
+                lines.push(Cow::Borrowed(line));
+            } else if !Self::skip_lenient_line(line, ctx) {
+                lines.push(Self::uppercase_lenient_line(line, ctx));
+            }
+        }
+        let content_lines_parts = lines
+            .iter()
+            .map(|line| ContentLineCaptures::new(line))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let start = content_lines_parts
+        let mut start_lines = content_lines_parts
             .iter()
-            .find(|parts| matches!(parts.property_name, PropertyName::DtStart))
-            .map(StartDateContentLine::try_from)
+            .filter(|parts| matches!(parts.property_name, PropertyName::DtStart));
+        let start = start_lines
+            .next()
+            .map(|parts| StartDateContentLine::parse(parts, ctx))
             .transpose()?;
+        // In lenient mode, the first start date is used
+        if start_lines.next().is_some() {
+            if !ctx.lenient {
+                return Err(ParseError::MultipleDtStart);
+            }
+            ctx.warn("Found multiple `DTSTART` properties, only the first one is used.");
+        }
 
         let mut content_lines = vec![];
 
         for parts in content_lines_parts {
             let line = match parts.property_name {
                 PropertyName::RRule => {
-                    let rrule = RRule::try_from(parts)?;
+                    let rrule = RRule::parse(&parts, ctx)?;
                     ContentLine::RRule(rrule)
                 }
                 PropertyName::ExRule => {
-                    let rrule = RRule::try_from(parts)?;
+                    // Without the `exrule` feature, the warning says that the rule is ignored
+                    if ctx.lenient && cfg!(feature = "exrule") {
+                        ctx.warn("`EXRULE` is deprecated by RFC 5545, use `EXDATE` instead.");
+                    }
+                    let rrule = RRule::parse(&parts, ctx)?;
                     ContentLine::ExRule(rrule)
                 }
                 PropertyName::RDate => ContentLine::RDate(TryFrom::try_from(parts)?),
@@ -73,6 +129,50 @@ impl FromStr for Grammar {
             content_lines,
         })
     }
+
+    /// Returns `true` for blank lines and `BEGIN`/`END` lines of a component, like
+    /// `BEGIN:VEVENT`, which are skipped in lenient mode.
+    fn skip_lenient_line(line: &str, ctx: &mut ParseContext) -> bool {
+        if line.trim().is_empty() {
+            ctx.warn("Skipped a blank line.");
+            return true;
+        }
+        let uppercase = line.trim().to_uppercase();
+        if uppercase.starts_with("BEGIN:") || uppercase.starts_with("END:") {
+            ctx.warn(format!("Skipped the `{}` line.", line.trim()));
+            return true;
+        }
+        false
+    }
+
+    /// Converts the line to uppercase, except for the value of a `TZID` parameter.
+    ///
+    /// Property names, parameters and rule parts are already case-insensitive, but
+    /// this also accepts lowercase property names, like `rrule:`, `VALUE` parameters,
+    /// like `VALUE=date`, and datetimes, like `19970902t090000z`, in lenient mode.
+    fn uppercase_lenient_line<'a>(line: &'a str, ctx: &mut ParseContext) -> Cow<'a, str> {
+        let uppercase = match line.split_once(':') {
+            Some((name_and_parameters, value)) => {
+                let name_and_parameters = name_and_parameters
+                    .split(';')
+                    .map(|part| match part.split_once('=') {
+                        Some((key, value)) if key.eq_ignore_ascii_case("TZID") => {
+                            format!("{}={}", key.to_uppercase(), value)
+                        }
+                        _ => part.to_uppercase(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(";");
+                format!("{}:{}", name_and_parameters, value.to_uppercase())
+            }
+            None => line.to_uppercase(),
+        };
+        if uppercase == line {
+            return Cow::Borrowed(line);
+        }
+        ctx.warn(format!("Converted `{line}` to uppercase."));
+        Cow::Owned(uppercase)
+    }
 }
 
 #[cfg(test)]
@@ -85,6 +185,63 @@ mod test {
     const UTC: Tz = Tz::UTC;
     const BERLIN: Tz = Tz::Europe__Berlin;
 
+    #[test]
+    fn skips_blank_and_component_lines_in_lenient_mode() {
+        let input =
+            "BEGIN:VEVENT\nDTSTART:19970902T090000Z\n\nRRULE:FREQ=YEARLY;COUNT=3\nEND:VEVENT";
+        assert!(Grammar::from_str(input).is_err());
+
+        let mut ctx = ParseContext::lenient();
+        assert_eq!(
+            Grammar::parse(input, &mut ctx),
+            Grammar::from_str("DTSTART:19970902T090000Z\nRRULE:FREQ=YEARLY;COUNT=3")
+        );
+        assert_eq!(ctx.warnings.len(), 3);
+    }
+
+    #[test]
+    fn warns_about_exrule_in_lenient_mode() {
+        let input = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=4\nEXRULE:FREQ=DAILY;COUNT=2";
+
+        let mut ctx = ParseContext::default();
+        assert!(Grammar::parse(input, &mut ctx).is_ok());
+        assert!(ctx.warnings.is_empty());
+
+        let mut ctx = ParseContext::lenient();
+        assert!(Grammar::parse(input, &mut ctx).is_ok());
+        assert_eq!(ctx.warnings.len(), usize::from(cfg!(feature = "exrule")));
+    }
+
+    #[test]
+    fn parses_lowercase_input_in_lenient_mode() {
+        let expected = Grammar::from_str(
+            "DTSTART;TZID=Europe/Berlin:19970902T090000\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=19971231T000000Z",
+        );
+        for input in [
+            "dtstart;tzid=Europe/Berlin:19970902t090000\nrrule:freq=weekly;byday=mo,we;until=19971231t000000z",
+            "DtStart;TzId=Europe/Berlin;Value=Date-Time:19970902T090000\nRRule:Freq=Weekly;ByDay=Mo,We;Until=19971231T000000Z",
+        ] {
+            assert!(Grammar::from_str(input).is_err());
+
+            let mut ctx = ParseContext::lenient();
+            assert_eq!(Grammar::parse(input, &mut ctx), expected);
+            assert_eq!(ctx.warnings.len(), 2);
+        }
+    }
+
+    #[test]
+    fn rejects_multiple_dtstart() {
+        let input = "DTSTART:19970902T090000Z\nDTSTART:20240101T090000Z\nRRULE:FREQ=YEARLY;COUNT=3";
+        assert_eq!(Grammar::from_str(input), Err(ParseError::MultipleDtStart));
+
+        let mut ctx = ParseContext::lenient();
+        assert_eq!(
+            Grammar::parse(input, &mut ctx),
+            Grammar::from_str("DTSTART:19970902T090000Z\nRRULE:FREQ=YEARLY;COUNT=3")
+        );
+        assert_eq!(ctx.warnings.len(), 1);
+    }
+
     #[test]
     fn parses_valid_input_to_grammar() {
         let tests = [
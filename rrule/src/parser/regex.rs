@@ -1,6 +1,7 @@
 //! Utility functions around the regexes we use for parsing rrule strings.
 use std::str::FromStr;
 
+use chrono::FixedOffset;
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 
@@ -84,6 +85,26 @@ impl ParsedDateString {
     }
 }
 
+lazy_static! {
+    static ref UTC_OFFSET_RE: Regex =
+        Regex::new(r"(?m)^(.+)([+-])([0-9]{2})([0-9]{2})$").expect("UTC_OFFSET_RE regex failed");
+}
+
+/// Splits a trailing numeric UTC offset, like `+0200` or `-0530`, from a datetime string.
+/// Returns `None` if the string doesn't end with a valid offset.
+pub(crate) fn split_utc_offset(val: &str) -> Option<(&str, FixedOffset)> {
+    let captures = UTC_OFFSET_RE.captures(val)?;
+    let datetime = captures.get(1)?.as_str();
+    let hours: i32 = captures.get(3)?.as_str().parse().ok()?;
+    let minutes: i32 = captures.get(4)?.as_str().parse().ok()?;
+    let seconds = (hours * 60 + minutes) * 60;
+    let offset = match captures.get(2)?.as_str() {
+        "-" => FixedOffset::west_opt(seconds),
+        _ => FixedOffset::east_opt(seconds),
+    }?;
+    Some((datetime, offset))
+}
+
 lazy_static! {
     static ref PARSE_PROPERTY_NAME_RE: Regex =
         Regex::new(r"(?m)^([A-Z]+?)[:;]").expect("PARSE_PROPERTY_NAME_RE regex failed");
@@ -0,0 +1,124 @@
+//! Windows timezone names and their IANA equivalent, based on the "001" territory
+//! of the CLDR `windowsZones.xml` mapping.
+
+const WINDOWS_TIMEZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("Pacific Standard Time (Mexico)", "America/Tijuana"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time (Mexico)", "America/Mazatlan"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("US Eastern Standard Time", "America/Indiana/Indianapolis"),
+    ("Venezuela Standard Time", "America/Caracas"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Pacific SA Standard Time", "America/Santiago"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("Argentina Standard Time", "America/Argentina/Buenos_Aires"),
+    ("UTC-02", "Etc/GMT+2"),
+    ("Azores Standard Time", "Atlantic/Azores"),
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("FLE Standard Time", "Europe/Kiev"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("E. Africa Standard Time", "Africa/Nairobi"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("Nepal Standard Time", "Asia/Kathmandu"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("Tasmania Standard Time", "Australia/Hobart"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("Tonga Standard Time", "Pacific/Tongatapu"),
+];
+
+/// Returns the IANA timezone name of a Windows timezone name, e.g. `Europe/Berlin`
+/// for `W. Europe Standard Time`.
+pub(crate) fn windows_to_iana(name: &str) -> Option<&'static str> {
+    WINDOWS_TIMEZONES
+        .iter()
+        .find(|(windows, _)| windows.eq_ignore_ascii_case(name))
+        .map(|(_, iana)| *iana)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::parser::datetime::parse_timezone;
+    use crate::Tz;
+
+    #[test]
+    fn all_windows_timezones_are_known() {
+        for (windows, iana) in WINDOWS_TIMEZONES {
+            assert!(
+                chrono_tz::Tz::from_str(iana).is_ok(),
+                "`{iana}` for `{windows}` is not a known timezone"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_windows_timezones() {
+        let tests = [
+            ("Pacific Standard Time", chrono_tz::Tz::America__Los_Angeles),
+            ("W. Europe Standard Time", chrono_tz::Tz::Europe__Berlin),
+            ("Tokyo Standard Time", chrono_tz::Tz::Asia__Tokyo),
+            (
+                "pacific standard time (mexico)",
+                chrono_tz::Tz::America__Tijuana,
+            ),
+        ];
+        for (windows, expected) in tests {
+            assert_eq!(parse_timezone(windows), Ok(Tz::Tz(expected)));
+        }
+    }
+
+    #[test]
+    fn parses_dtstart_with_windows_timezone() {
+        let set: crate::RRuleSet =
+            "DTSTART;TZID=Pacific Standard Time:19970902T090000\nRRULE:FREQ=DAILY;COUNT=1"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            set.get_dt_start().timezone(),
+            Tz::Tz(chrono_tz::Tz::America__Los_Angeles)
+        );
+    }
+}
@@ -30,6 +30,10 @@ pub fn test_recurring_rrule(
             RRuleError::ParserError(e) => e.to_string(),
             RRuleError::ValidationError(e) => e.to_string(),
             RRuleError::IterError(e) => e,
+            e @ (RRuleError::InfiniteRule
+            | RRuleError::LimitReached
+            | RRuleError::TimeOnDateOnlyEvent
+            | RRuleError::Multiple(_)) => e.to_string(),
         })
         .unwrap();
     let res = if limited {
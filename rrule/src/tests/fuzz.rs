@@ -0,0 +1,128 @@
+use crate::RRuleSet;
+
+const PROPERTIES: &[&str] = &[
+    "DTSTART",
+    "DTSTART;TZID=Europe/Berlin",
+    "DTSTART;VALUE=DATE",
+    "RRULE",
+    "EXRULE",
+    "RDATE",
+    "EXDATE",
+    "EXDATE;TZID=UTC",
+    "X-NAME",
+];
+
+const KEYS: &[&str] = &[
+    "FREQ",
+    "UNTIL",
+    "COUNT",
+    "INTERVAL",
+    "BYSETPOS",
+    "BYMONTH",
+    "BYMONTHDAY",
+    "BYYEARDAY",
+    "BYWEEKNO",
+    "BYWEEKDAY",
+    "BYDAY",
+    "BYHOUR",
+    "BYMINUTE",
+    "BYSECOND",
+    "BYEASTER",
+    "WKST",
+];
+
+const VALUES: &[&str] = &[
+    "YEARLY",
+    "MONTHLY",
+    "WEEKLY",
+    "DAILY",
+    "SECONDLY",
+    "MO",
+    "SU",
+    "19970902T090000",
+    "20120201",
+    "T",
+    "Z",
+    ",",
+    "-",
+    "+",
+    "0",
+    "1",
+    "9",
+    "99",
+    "366",
+    "-1",
+    "é",
+    "ß",
+    "日本",
+];
+
+/// Small xorshift generator, so the test is deterministic and needs no extra dependencies.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+
+    fn printable_ascii(&mut self) -> char {
+        char::from(b' ' + self.below(95) as u8)
+    }
+}
+
+/// Generates lines that look roughly like content lines, with random values.
+fn random_input(rng: &mut XorShift) -> String {
+    let mut lines = Vec::new();
+    if rng.below(3) > 0 {
+        // A valid start date, so the other lines aren't rejected right away
+        lines.push("DTSTART:19970902T090000Z".to_string());
+    }
+    for _ in 0..=rng.below(3) {
+        let mut line = format!("{}:", rng.pick(PROPERTIES));
+        for part in 0..rng.below(5) {
+            if part > 0 {
+                line.push(';');
+            }
+            line.push_str(rng.pick(KEYS));
+            line.push('=');
+            for _ in 0..rng.below(4) {
+                if rng.below(3) == 0 {
+                    line.push(rng.printable_ascii());
+                } else {
+                    line.push_str(rng.pick(VALUES));
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Generates any printable ASCII.
+fn random_ascii(rng: &mut XorShift) -> String {
+    (0..rng.below(80)).map(|_| rng.printable_ascii()).collect()
+}
+
+#[test]
+fn parsing_random_input_does_not_panic() {
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    for _ in 0..50_000 {
+        for input in [random_input(&mut rng), random_ascii(&mut rng)] {
+            let result = std::panic::catch_unwind(|| {
+                let _ = input.parse::<RRuleSet>();
+            });
+            assert!(result.is_ok(), "panicked on input: {input:?}");
+        }
+    }
+}
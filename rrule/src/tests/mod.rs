@@ -3,6 +3,7 @@
 mod common;
 mod datetime;
 mod daylight_saving;
+mod fuzz;
 mod regression;
 mod rfc_tests;
 mod rrule;
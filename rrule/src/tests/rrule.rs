@@ -1,6 +1,6 @@
 use crate::core::Tz;
 use crate::tests::common::{test_recurring_rrule, ymd_hms};
-use crate::{Frequency, NWeekday, RRule, RRuleSet, Weekday};
+use crate::{Frequency, NWeekday, ParseError, RRule, RRuleSet, Skip, Validated, Weekday};
 use chrono::{Datelike, TimeZone};
 
 #[test]
@@ -173,6 +173,48 @@ fn yearly_by_month() {
     );
 }
 
+#[test]
+fn yearly_by_multiple_months() {
+    // The months are expanded in chronological order, on the day of `DTSTART`,
+    // even if the month of `DTSTART` isn't one of them
+    let rrule = RRule {
+        freq: Frequency::Yearly,
+        count: Some(8),
+        by_month: vec![12, 3, 9, 6],
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule.clone(),
+        true,
+        ymd_hms(2024, 1, 15, 9, 0, 0),
+        &[
+            ymd_hms(2024, 3, 15, 9, 0, 0),
+            ymd_hms(2024, 6, 15, 9, 0, 0),
+            ymd_hms(2024, 9, 15, 9, 0, 0),
+            ymd_hms(2024, 12, 15, 9, 0, 0),
+            ymd_hms(2025, 3, 15, 9, 0, 0),
+            ymd_hms(2025, 6, 15, 9, 0, 0),
+            ymd_hms(2025, 9, 15, 9, 0, 0),
+            ymd_hms(2025, 12, 15, 9, 0, 0),
+        ],
+    );
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2024, 6, 15, 9, 0, 0),
+        &[
+            ymd_hms(2024, 6, 15, 9, 0, 0),
+            ymd_hms(2024, 9, 15, 9, 0, 0),
+            ymd_hms(2024, 12, 15, 9, 0, 0),
+            ymd_hms(2025, 3, 15, 9, 0, 0),
+            ymd_hms(2025, 6, 15, 9, 0, 0),
+            ymd_hms(2025, 9, 15, 9, 0, 0),
+            ymd_hms(2025, 12, 15, 9, 0, 0),
+            ymd_hms(2026, 3, 15, 9, 0, 0),
+        ],
+    );
+}
+
 #[test]
 fn yearly_by_monthday() {
     let rrule = RRule {
@@ -542,6 +584,38 @@ fn yearly_by_weekno_and_weekday() {
     );
 }
 
+#[test]
+fn yearly_by_weekno_and_weekday_across_year_boundary() {
+    // ISO week 1 of 2019 and 2020 starts in the previous calendar year
+    let rrule = RRule {
+        freq: Frequency::Yearly,
+        count: Some(10),
+        by_weekday: vec![NWeekday::Every(Weekday::Mon)],
+        by_hour: vec![9],
+        by_week_no: vec![1, 2],
+        by_minute: vec![0],
+        by_second: vec![0],
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2018, 1, 1, 9, 0, 0),
+        &[
+            ymd_hms(2018, 1, 1, 9, 0, 0),
+            ymd_hms(2018, 1, 8, 9, 0, 0),
+            ymd_hms(2018, 12, 31, 9, 0, 0),
+            ymd_hms(2019, 1, 7, 9, 0, 0),
+            ymd_hms(2019, 12, 30, 9, 0, 0),
+            ymd_hms(2020, 1, 6, 9, 0, 0),
+            ymd_hms(2021, 1, 4, 9, 0, 0),
+            ymd_hms(2021, 1, 11, 9, 0, 0),
+            ymd_hms(2022, 1, 3, 9, 0, 0),
+            ymd_hms(2022, 1, 10, 9, 0, 0),
+        ],
+    );
+}
+
 #[test]
 fn yearly_by_weekno_and_weekday_large() {
     let rrule = RRule {
@@ -829,6 +903,45 @@ fn monthly() {
     );
 }
 
+#[test]
+fn monthly_without_by_month_day_uses_dt_start_day() {
+    let rrule = RRule {
+        freq: Frequency::Monthly,
+        count: Some(3),
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(1997, 9, 15, 9, 0, 0),
+        &[
+            ymd_hms(1997, 9, 15, 9, 0, 0),
+            ymd_hms(1997, 10, 15, 9, 0, 0),
+            ymd_hms(1997, 11, 15, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn monthly_without_by_month_day_skips_months_without_dt_start_day() {
+    let rrule = RRule {
+        freq: Frequency::Monthly,
+        count: Some(4),
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(1997, 1, 31, 9, 0, 0),
+        &[
+            ymd_hms(1997, 1, 31, 9, 0, 0),
+            ymd_hms(1997, 3, 31, 9, 0, 0),
+            ymd_hms(1997, 5, 31, 9, 0, 0),
+            ymd_hms(1997, 7, 31, 9, 0, 0),
+        ],
+    );
+}
+
 #[test]
 fn monthly_interval() {
     let rrule = RRule {
@@ -1070,6 +1183,38 @@ fn monthly_by_monthday() {
     );
 }
 
+#[test]
+fn monthly_by_multiple_monthdays_with_negative() {
+    let rrule = RRule {
+        freq: Frequency::Monthly,
+        count: Some(12),
+        by_hour: vec![9],
+        by_minute: vec![0],
+        by_second: vec![0],
+        by_month_day: vec![15, -1, 1],
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(1998, 1, 1, 9, 0, 0),
+        &[
+            ymd_hms(1998, 1, 1, 9, 0, 0),
+            ymd_hms(1998, 1, 15, 9, 0, 0),
+            ymd_hms(1998, 1, 31, 9, 0, 0),
+            ymd_hms(1998, 2, 1, 9, 0, 0),
+            ymd_hms(1998, 2, 15, 9, 0, 0),
+            ymd_hms(1998, 2, 28, 9, 0, 0),
+            ymd_hms(1998, 3, 1, 9, 0, 0),
+            ymd_hms(1998, 3, 15, 9, 0, 0),
+            ymd_hms(1998, 3, 31, 9, 0, 0),
+            ymd_hms(1998, 4, 1, 9, 0, 0),
+            ymd_hms(1998, 4, 15, 9, 0, 0),
+            ymd_hms(1998, 4, 30, 9, 0, 0),
+        ],
+    );
+}
+
 #[test]
 fn monthly_by_month_and_monthday() {
     let rrule = RRule {
@@ -1502,6 +1647,26 @@ fn monthly_by_setpos() {
     );
 }
 
+#[test]
+fn weekly_without_by_weekday_uses_dt_start_weekday() {
+    // DTSTART is a Wednesday
+    let rrule = RRule {
+        freq: Frequency::Weekly,
+        count: Some(3),
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(1997, 9, 3, 9, 0, 0),
+        &[
+            ymd_hms(1997, 9, 3, 9, 0, 0),
+            ymd_hms(1997, 9, 10, 9, 0, 0),
+            ymd_hms(1997, 9, 17, 9, 0, 0),
+        ],
+    );
+}
+
 #[test]
 fn weekly() {
     let rrule = RRule {
@@ -2019,6 +2184,61 @@ fn daily_by_weekday() {
     );
 }
 
+#[test]
+fn daily_by_weekday_limits() {
+    // For a daily rule, BYDAY limits the days instead of expanding them, so a `DTSTART`
+    // on another weekday isn't a recurrence
+    let rrule = RRule {
+        freq: Frequency::Daily,
+        count: Some(6),
+        by_weekday: vec![
+            NWeekday::Every(Weekday::Mon),
+            NWeekday::Every(Weekday::Wed),
+            NWeekday::Every(Weekday::Fri),
+        ],
+        ..Default::default()
+    };
+    let set = rrule.build(ymd_hms(2024, 1, 2, 9, 0, 0)).unwrap();
+    let dates = set.all(100).dates;
+    assert_eq!(
+        dates,
+        vec![
+            ymd_hms(2024, 1, 3, 9, 0, 0),
+            ymd_hms(2024, 1, 5, 9, 0, 0),
+            ymd_hms(2024, 1, 8, 9, 0, 0),
+            ymd_hms(2024, 1, 10, 9, 0, 0),
+            ymd_hms(2024, 1, 12, 9, 0, 0),
+            ymd_hms(2024, 1, 15, 9, 0, 0),
+        ]
+    );
+
+    // The interval applies to the days before they are limited
+    let rrule = RRule {
+        freq: Frequency::Daily,
+        interval: 2,
+        count: Some(6),
+        by_weekday: vec![
+            NWeekday::Every(Weekday::Mon),
+            NWeekday::Every(Weekday::Wed),
+            NWeekday::Every(Weekday::Fri),
+        ],
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2024, 1, 1, 9, 0, 0),
+        &[
+            ymd_hms(2024, 1, 1, 9, 0, 0),
+            ymd_hms(2024, 1, 3, 9, 0, 0),
+            ymd_hms(2024, 1, 5, 9, 0, 0),
+            ymd_hms(2024, 1, 15, 9, 0, 0),
+            ymd_hms(2024, 1, 17, 9, 0, 0),
+            ymd_hms(2024, 1, 19, 9, 0, 0),
+        ],
+    );
+}
+
 #[test]
 fn daily_by_month_and_weekday() {
     let rrule = RRule {
@@ -3726,6 +3946,24 @@ fn until_with_date() {
     );
 }
 
+#[test]
+fn until_is_inclusive() {
+    // An occurrence falling exactly on `UNTIL` is part of the series.
+    let dates = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;UNTIL=19970904T090000Z"
+        .parse::<RRuleSet>()
+        .unwrap()
+        .all(u16::MAX)
+        .dates;
+    assert_eq!(
+        dates,
+        vec![
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 3, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+        ]
+    );
+}
+
 #[test]
 fn week_start_interval_mo() {
     let rrule = RRule {
@@ -3938,3 +4176,732 @@ fn test_between_inclusive_both_hit() {
 
     assert_eq!(vec![after, middle, before], rrule.all_unchecked());
 }
+
+#[test]
+fn parse_single_validated_rrule() {
+    let rrule: RRule = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3"
+        .parse()
+        .unwrap();
+    assert_eq!(rrule.get_freq(), Frequency::Weekly);
+    assert_eq!(rrule.get_count(), Some(3));
+    // Filled from `DTSTART` during validation
+    assert_eq!(rrule.get_by_weekday(), &[NWeekday::Every(Weekday::Tue)]);
+}
+
+#[test]
+fn parse_single_validated_rrule_rejects_multiple_rrules() {
+    let res: Result<RRule, _> =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3\nRRULE:FREQ=DAILY;COUNT=3".parse();
+    assert_eq!(res, Err(ParseError::ExpectedSingleRRule.into()));
+
+    let res: Result<RRule, _> =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3\nEXDATE:19970909T090000Z".parse();
+    assert_eq!(res, Err(ParseError::ExpectedSingleRRule.into()));
+}
+
+#[test]
+fn yearly_on_leap_day_skips_non_leap_years() {
+    let rrule = RRule {
+        freq: Frequency::Yearly,
+        until: Some(ymd_hms(2029, 1, 1, 0, 0, 0)),
+        by_month: vec![2],
+        by_month_day: vec![29],
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2020, 2, 29, 9, 0, 0),
+        &[
+            ymd_hms(2020, 2, 29, 9, 0, 0),
+            ymd_hms(2024, 2, 29, 9, 0, 0),
+            ymd_hms(2028, 2, 29, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn monthly_from_the_31st_skips_short_months() {
+    // Months without a 31st day are skipped instead of clamped to their last day.
+    let rrule = RRule {
+        freq: Frequency::Monthly,
+        count: Some(4),
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2023, 1, 31, 9, 0, 0),
+        &[
+            ymd_hms(2023, 1, 31, 9, 0, 0),
+            ymd_hms(2023, 3, 31, 9, 0, 0),
+            ymd_hms(2023, 5, 31, 9, 0, 0),
+            ymd_hms(2023, 7, 31, 9, 0, 0),
+        ],
+    );
+
+    // With an interval, only the months that are stepped on are considered.
+    let rrule = RRule {
+        freq: Frequency::Monthly,
+        interval: 3,
+        count: Some(4),
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2023, 1, 31, 9, 0, 0),
+        &[
+            ymd_hms(2023, 1, 31, 9, 0, 0),
+            ymd_hms(2023, 7, 31, 9, 0, 0),
+            ymd_hms(2023, 10, 31, 9, 0, 0),
+            ymd_hms(2024, 1, 31, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn monthly_on_the_31st_with_skip() {
+    let dt_start = ymd_hms(2023, 1, 31, 9, 0, 0);
+    for (skip, expected) in [
+        (
+            Skip::Omit,
+            [
+                ymd_hms(2023, 1, 31, 9, 0, 0),
+                ymd_hms(2023, 3, 31, 9, 0, 0),
+                ymd_hms(2023, 5, 31, 9, 0, 0),
+                ymd_hms(2023, 7, 31, 9, 0, 0),
+                ymd_hms(2023, 8, 31, 9, 0, 0),
+                ymd_hms(2023, 10, 31, 9, 0, 0),
+            ],
+        ),
+        (
+            Skip::Backward,
+            [
+                ymd_hms(2023, 1, 31, 9, 0, 0),
+                ymd_hms(2023, 2, 28, 9, 0, 0),
+                ymd_hms(2023, 3, 31, 9, 0, 0),
+                ymd_hms(2023, 4, 30, 9, 0, 0),
+                ymd_hms(2023, 5, 31, 9, 0, 0),
+                ymd_hms(2023, 6, 30, 9, 0, 0),
+            ],
+        ),
+        (
+            Skip::Forward,
+            [
+                ymd_hms(2023, 1, 31, 9, 0, 0),
+                ymd_hms(2023, 3, 1, 9, 0, 0),
+                ymd_hms(2023, 3, 31, 9, 0, 0),
+                ymd_hms(2023, 5, 1, 9, 0, 0),
+                ymd_hms(2023, 5, 31, 9, 0, 0),
+                ymd_hms(2023, 7, 1, 9, 0, 0),
+            ],
+        ),
+    ] {
+        let rrule = RRule::new(Frequency::Monthly)
+            .by_month_day(vec![31])
+            .count(6)
+            .skip(skip);
+        test_recurring_rrule(rrule.clone(), true, dt_start, &expected);
+
+        // The rule round-trips through its string representation
+        let set = rrule.build(dt_start).unwrap();
+        let parsed: RRuleSet = set.to_string().parse().unwrap();
+        assert_eq!(parsed, set);
+    }
+
+    // A date that is valid in both directions isn't repeated
+    let rrule = RRule::new(Frequency::Monthly)
+        .by_month_day(vec![29, 30, 31])
+        .count(4)
+        .skip(Skip::Backward);
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2023, 1, 29, 9, 0, 0),
+        &[
+            ymd_hms(2023, 1, 29, 9, 0, 0),
+            ymd_hms(2023, 1, 30, 9, 0, 0),
+            ymd_hms(2023, 1, 31, 9, 0, 0),
+            ymd_hms(2023, 2, 28, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn monthly_skip_into_a_generated_date() {
+    // The 31st of February is moved to the 1st of March, which is generated anyway
+    let set: RRuleSet = "DTSTART:20230101T090000Z\n\
+        RRULE:RSCALE=GREGORIAN;FREQ=MONTHLY;BYMONTHDAY=1,31;SKIP=FORWARD;COUNT=6"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![
+            ymd_hms(2023, 1, 1, 9, 0, 0),
+            ymd_hms(2023, 1, 31, 9, 0, 0),
+            ymd_hms(2023, 2, 1, 9, 0, 0),
+            ymd_hms(2023, 3, 1, 9, 0, 0),
+            ymd_hms(2023, 3, 31, 9, 0, 0),
+            ymd_hms(2023, 4, 1, 9, 0, 0),
+        ]
+    );
+
+    // The 31st to last day of February is moved to the 31st of January
+    let set: RRuleSet = "DTSTART:20230101T090000Z\n\
+        RRULE:RSCALE=GREGORIAN;FREQ=MONTHLY;BYMONTHDAY=-1,-31;SKIP=BACKWARD;COUNT=6"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![
+            ymd_hms(2023, 1, 1, 9, 0, 0),
+            ymd_hms(2023, 1, 31, 9, 0, 0),
+            ymd_hms(2023, 2, 28, 9, 0, 0),
+            ymd_hms(2023, 3, 1, 9, 0, 0),
+            ymd_hms(2023, 3, 31, 9, 0, 0),
+            ymd_hms(2023, 4, 30, 9, 0, 0),
+        ]
+    );
+
+    // With an interval, the neighbouring month isn't generated, so the moved day is kept
+    let set: RRuleSet = "DTSTART:20230201T090000Z\n\
+        RRULE:RSCALE=GREGORIAN;FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=1,31;SKIP=FORWARD;COUNT=4"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![
+            ymd_hms(2023, 2, 1, 9, 0, 0),
+            ymd_hms(2023, 3, 1, 9, 0, 0),
+            ymd_hms(2023, 4, 1, 9, 0, 0),
+            ymd_hms(2023, 5, 1, 9, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn yearly_on_leap_day_with_skip() {
+    // Example from RFC 7529
+    let set: RRuleSet =
+        "DTSTART:20160229T090000Z\nRRULE:RSCALE=GREGORIAN;FREQ=YEARLY;SKIP=FORWARD;COUNT=5"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![
+            ymd_hms(2016, 2, 29, 9, 0, 0),
+            ymd_hms(2017, 3, 1, 9, 0, 0),
+            ymd_hms(2018, 3, 1, 9, 0, 0),
+            ymd_hms(2019, 3, 1, 9, 0, 0),
+            ymd_hms(2020, 2, 29, 9, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn active_weekdays() {
+    let rrule = RRule {
+        freq: Frequency::Weekly,
+        by_weekday: vec![
+            NWeekday::Every(Weekday::Mon),
+            NWeekday::Every(Weekday::Wed),
+            NWeekday::Every(Weekday::Fri),
+        ],
+        ..Default::default()
+    }
+    .validate(ymd_hms(1997, 9, 2, 9, 0, 0))
+    .unwrap();
+    assert_eq!(
+        rrule.active_weekdays(),
+        [Weekday::Mon, Weekday::Wed, Weekday::Fri].into()
+    );
+
+    // Without BYDAY, the weekday of DTSTART is used
+    let rrule = RRule::new(Frequency::Weekly)
+        .validate(ymd_hms(1997, 9, 2, 9, 0, 0))
+        .unwrap();
+    assert_eq!(rrule.active_weekdays(), [Weekday::Tue].into());
+
+    let rrule = RRule::new(Frequency::Daily)
+        .validate(ymd_hms(1997, 9, 2, 9, 0, 0))
+        .unwrap();
+    assert_eq!(rrule.active_weekdays().len(), 7);
+}
+
+#[test]
+fn nth_weekday_map() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=MONTHLY;BYDAY=1MO,3MO,-1FR,SU"
+        .parse()
+        .unwrap();
+    let map = set.get_rrule()[0].nth_weekday_map();
+    assert_eq!(
+        map,
+        [
+            (Weekday::Mon, vec![1, 3]),
+            (Weekday::Fri, vec![-1]),
+            (Weekday::Sun, vec![]),
+        ]
+        .into()
+    );
+}
+
+#[test]
+fn monthly_by_set_pos_with_weekday_and_monthday() {
+    // BYSETPOS selects from the candidates in chronological order,
+    // regardless of the order of the BYDAY and BYMONTHDAY values.
+    let rrule = RRule {
+        freq: Frequency::Monthly,
+        count: Some(4),
+        by_weekday: vec![
+            NWeekday::Every(Weekday::Fri),
+            NWeekday::Every(Weekday::Mon),
+            NWeekday::Every(Weekday::Tue),
+            NWeekday::Every(Weekday::Wed),
+            NWeekday::Every(Weekday::Thu),
+        ],
+        by_month_day: vec![7, 1, 2, 3, 4, 5, 6],
+        by_set_pos: vec![2],
+        by_hour: vec![9],
+        by_minute: vec![0],
+        by_second: vec![0],
+        ..Default::default()
+    };
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(1997, 9, 2, 9, 0, 0),
+        &[
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 10, 2, 9, 0, 0),
+            ymd_hms(1997, 11, 4, 9, 0, 0),
+            ymd_hms(1997, 12, 2, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn build_with_try_from() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let rrule = RRule::new(Frequency::Weekly)
+        .count(3)
+        .by_weekday(vec![NWeekday::Every(Weekday::Mon)]);
+    assert_eq!(
+        RRuleSet::try_from((rrule.clone(), dt_start)),
+        rrule.build(dt_start)
+    );
+
+    let rrule = RRule::new(Frequency::Daily).by_hour(vec![24]);
+    assert_eq!(
+        RRuleSet::try_from((rrule.clone(), dt_start)),
+        rrule.clone().build(dt_start)
+    );
+    assert!(RRuleSet::try_from((rrule, dt_start)).is_err());
+}
+
+#[test]
+fn validate_with_try_from() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let rrule = RRule::new(Frequency::Monthly)
+        .count(3)
+        .by_month_day(vec![1, -1]);
+    assert_eq!(
+        RRule::<Validated>::try_from((rrule.clone(), dt_start)),
+        rrule.validate(dt_start)
+    );
+
+    let rrule = RRule::new(Frequency::Daily).by_hour(vec![24]);
+    assert!(RRule::<Validated>::try_from((rrule, dt_start)).is_err());
+}
+
+#[test]
+fn check_without_validating() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+
+    let rrule = RRule::new(Frequency::Weekly).by_weekday(vec![NWeekday::Every(Weekday::Mon)]);
+    assert_eq!(rrule.check(dt_start), Ok(()));
+    // The rrule can still be used after checking it
+    assert!(rrule.validate(dt_start).is_ok());
+
+    let invalid = [
+        (
+            RRule::new(Frequency::Monthly).by_week_no(vec![1]),
+            "invalid_by_rule_and_frequency",
+        ),
+        (
+            RRule::new(Frequency::Weekly).by_weekday(vec![NWeekday::Nth(1, Weekday::Mon)]),
+            "invalid_by_day_for_freq",
+        ),
+        (
+            RRule::new(Frequency::Daily).by_year_day(vec![1]),
+            "invalid_by_rule_and_frequency",
+        ),
+        (
+            RRule::new(Frequency::Daily).by_hour(vec![24]),
+            "invalid_field_value_range",
+        ),
+        (
+            RRule::new(Frequency::Daily).until(ymd_hms(1997, 9, 1, 9, 0, 0)),
+            "until_before_start",
+        ),
+    ];
+    for (rrule, code) in invalid {
+        assert_eq!(rrule.check(dt_start).map_err(|err| err.code()), Err(code));
+    }
+}
+
+#[test]
+fn simple_rules_match_the_general_path() {
+    // `BYSETPOS=1` doesn't change the recurrences, but skips the fast path. Sub-daily rules
+    // need another BYxxx rule part for it, so they get every month as well.
+    let all_months = ";BYMONTH=1,2,3,4,5,6,7,8,9,10,11,12;BYSETPOS=1";
+    let rules = [
+        ("FREQ=DAILY;COUNT=400", ";BYSETPOS=1"),
+        (
+            "FREQ=DAILY;INTERVAL=3;UNTIL=20230101T000000Z",
+            ";BYSETPOS=1",
+        ),
+        ("FREQ=WEEKLY;INTERVAL=2;COUNT=100", ";BYSETPOS=1"),
+        ("FREQ=MONTHLY;COUNT=50", ";BYSETPOS=1"),
+        ("FREQ=MONTHLY;INTERVAL=5;COUNT=50", ";BYSETPOS=1"),
+        ("FREQ=YEARLY;COUNT=20", ";BYSETPOS=1"),
+        ("FREQ=YEARLY;INTERVAL=3;COUNT=20", ";BYSETPOS=1"),
+        ("FREQ=HOURLY;INTERVAL=5;COUNT=400", all_months),
+        ("FREQ=MINUTELY;INTERVAL=7;COUNT=400", all_months),
+        ("FREQ=SECONDLY;INTERVAL=13;COUNT=400", all_months),
+    ];
+    // Crosses several DST transitions, and the start time doesn't exist on 2021-03-28
+    for dt_start in [
+        "DTSTART;TZID=Europe/Berlin:20210301T023000",
+        "DTSTART:20210115T090000Z",
+    ] {
+        for (rule, general_parts) in rules {
+            let simple: RRuleSet = format!("{dt_start}\nRRULE:{rule}").parse().unwrap();
+            assert!(simple.get_rrule()[0]
+                .iter_with_ctx(*simple.get_dt_start(), true)
+                .simple
+                .is_some());
+
+            let general: RRuleSet = format!("{dt_start}\nRRULE:{rule}{general_parts}")
+                .parse()
+                .unwrap();
+            assert!(general.get_rrule()[0]
+                .iter_with_ctx(*general.get_dt_start(), true)
+                .simple
+                .is_none());
+
+            assert_eq!(simple.all(500).dates, general.all(500).dates, "{rule}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "by-easter")]
+fn parse_yearly_by_easter() {
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=YEARLY;BYEASTER=0;COUNT=2"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![ymd_hms(2024, 3, 31, 9, 0, 0), ymd_hms(2025, 4, 20, 9, 0, 0)]
+    );
+
+    // Good Friday
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=YEARLY;BYEASTER=-2;COUNT=2"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![ymd_hms(2024, 3, 29, 9, 0, 0), ymd_hms(2025, 4, 18, 9, 0, 0)]
+    );
+}
+
+#[test]
+fn rejects_oversized_by_lists() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let res = RRule::new(Frequency::Monthly)
+        .by_month_day(vec![1; 100])
+        .validate(dt_start);
+    assert_eq!(res.map_err(|err| err.code()), Err("too_many_values"));
+
+    let res = RRule::new(Frequency::Yearly)
+        .by_year_day(vec![1; 1000])
+        .validate(dt_start);
+    assert_eq!(res.map_err(|err| err.code()), Err("too_many_values"));
+
+    // Parsing removes duplicate values, so parsed lists are never too long
+    let by_month_day = vec!["1"; 100].join(",");
+    let set: RRuleSet =
+        format!("DTSTART:19970902T090000Z\nRRULE:FREQ=MONTHLY;BYMONTHDAY={by_month_day}")
+            .parse()
+            .unwrap();
+    assert_eq!(set.get_rrule()[0].get_by_month_day(), &[1]);
+}
+
+#[test]
+fn by_set_pos_range() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    for by_set_pos in [1, -1, 366, -366] {
+        let rrule = RRule::new(Frequency::Yearly)
+            .count(2)
+            .by_weekday(vec![NWeekday::Every(Weekday::Mon)])
+            .by_set_pos(vec![by_set_pos]);
+        assert!(rrule.build(dt_start).is_ok(), "{by_set_pos}");
+    }
+
+    let rrule = RRule::new(Frequency::Yearly).by_set_pos(vec![0]);
+    assert_eq!(
+        rrule.build(dt_start).map_err(|err| err.code()),
+        Err("invalid_field_value")
+    );
+    let rrule = RRule::new(Frequency::Yearly).by_set_pos(vec![400]);
+    assert_eq!(
+        rrule.build(dt_start).map_err(|err| err.code()),
+        Err("invalid_field_value_range_with_freq")
+    );
+}
+
+#[test]
+fn week_start_is_only_displayed_if_not_monday() {
+    let rrule = RRule::new(Frequency::Weekly).week_start(Weekday::Mon);
+    assert_eq!(rrule.to_string(), "FREQ=WEEKLY");
+
+    let rrule = RRule::new(Frequency::Weekly).week_start(Weekday::Sun);
+    assert_eq!(rrule.to_string(), "FREQ=WEEKLY;WKST=SU");
+
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;COUNT=2;WKST=MO"
+        .parse()
+        .unwrap();
+    assert!(!set.to_string().contains("WKST"));
+}
+
+#[test]
+fn large_interval() {
+    // The next recurrence is out of the supported year range, so the iteration ends
+    let rrule = RRule::new(Frequency::Yearly).interval(u16::MAX);
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2024, 1, 1, 9, 0, 0),
+        &[ymd_hms(2024, 1, 1, 9, 0, 0)],
+    );
+
+    // 65535 months are 5461 years and 3 months
+    let rrule = RRule::new(Frequency::Monthly)
+        .interval(u16::MAX)
+        .by_month_day(vec![1, 2]);
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2024, 1, 1, 9, 0, 0),
+        &[
+            ymd_hms(2024, 1, 1, 9, 0, 0),
+            ymd_hms(2024, 1, 2, 9, 0, 0),
+            ymd_hms(7485, 4, 1, 9, 0, 0),
+            ymd_hms(7485, 4, 2, 9, 0, 0),
+        ],
+    );
+
+    let rrule = RRule::new(Frequency::Weekly)
+        .interval(u16::MAX)
+        .by_weekday(vec![NWeekday::Every(Weekday::Mon)])
+        .count(3);
+    test_recurring_rrule(
+        rrule,
+        true,
+        ymd_hms(2024, 1, 1, 9, 0, 0),
+        &[
+            ymd_hms(2024, 1, 1, 9, 0, 0),
+            ymd_hms(3280, 1, 1, 9, 0, 0),
+            ymd_hms(4536, 1, 2, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn interval_before_freq() {
+    let dt_start = "DTSTART:19970902T090000Z";
+    let interval_first: RRuleSet = format!("{dt_start}\nRRULE:INTERVAL=2;FREQ=WEEKLY;COUNT=3")
+        .parse()
+        .unwrap();
+    assert_eq!(
+        interval_first.clone().all(10).dates,
+        vec![
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 16, 9, 0, 0),
+            ymd_hms(1997, 9, 30, 9, 0, 0),
+        ]
+    );
+    assert_eq!(
+        interval_first.get_rrule()[0].to_string(),
+        "FREQ=WEEKLY;COUNT=3;INTERVAL=2;BYHOUR=9;BYMINUTE=0;BYSECOND=0;BYDAY=TU"
+    );
+}
+
+#[test]
+fn occurrence_count_between() {
+    let dt_start = ymd_hms(2024, 1, 1, 9, 0, 0);
+    let rrule = RRule::new(Frequency::Weekly)
+        .by_weekday(vec![
+            NWeekday::Every(Weekday::Mon),
+            NWeekday::Every(Weekday::Thu),
+        ])
+        .validate(dt_start)
+        .unwrap();
+
+    // February to April 2024 has 13 Mondays and 13 Thursdays
+    let after = ymd_hms(2024, 2, 1, 0, 0, 0);
+    let before = ymd_hms(2024, 4, 30, 23, 59, 59);
+    assert_eq!(rrule.occurrence_count_between(dt_start, after, before), 26);
+
+    // Both bounds are inclusive
+    let after = ymd_hms(2024, 2, 1, 9, 0, 0);
+    let before = ymd_hms(2024, 2, 5, 9, 0, 0);
+    assert_eq!(rrule.occurrence_count_between(dt_start, after, before), 2);
+
+    // Exclusions of a set aren't taken into account
+    let set = RRuleSet::new(dt_start).rrule(rrule.clone()).exdate(after);
+    assert_eq!(set.after(after).before(before).all(10).dates.len(), 1);
+    assert_eq!(rrule.occurrence_count_between(dt_start, after, before), 2);
+}
+
+#[test]
+fn daily_at_repeated_time_on_fall_back_day() {
+    // Clocks in Berlin go back from 03:00 to 02:00 on 2024-10-27, so 02:30 occurs twice
+    let berlin = |day, hour| {
+        Tz::Europe__Berlin
+            .with_ymd_and_hms(2024, 10, day, hour, 30, 0)
+            .earliest()
+            .unwrap()
+    };
+    for rule in [
+        "FREQ=DAILY;COUNT=4",
+        "FREQ=DAILY;COUNT=4;BYMONTH=10;BYSETPOS=1",
+    ] {
+        let set: RRuleSet = format!("DTSTART;TZID=Europe/Berlin:20241025T023000\nRRULE:{rule}")
+            .parse()
+            .unwrap();
+        let dates = set.all(10).dates;
+        assert_eq!(
+            dates,
+            vec![berlin(25, 2), berlin(26, 2), berlin(27, 2), berlin(28, 2)],
+            "{rule}"
+        );
+        // The first 02:30, still in summer time
+        assert_eq!(dates[2].to_rfc3339(), "2024-10-27T02:30:00+02:00");
+    }
+}
+
+#[test]
+fn matches() {
+    let dt_start = ymd_hms(2024, 1, 15, 9, 0, 0);
+    let rrule = RRule::new(Frequency::Monthly)
+        .interval(2)
+        .by_month_day(vec![15, -1])
+        .validate(dt_start)
+        .unwrap();
+
+    for dt in [
+        ymd_hms(2024, 1, 15, 9, 0, 0),
+        ymd_hms(2024, 1, 31, 9, 0, 0),
+        ymd_hms(2024, 3, 15, 9, 0, 0),
+        ymd_hms(2024, 3, 31, 9, 0, 0),
+        ymd_hms(2030, 11, 30, 9, 0, 0),
+    ] {
+        assert_eq!(rrule.matches(dt_start, dt), Ok(true), "{dt}");
+    }
+    for dt in [
+        // Before the start
+        ymd_hms(2023, 11, 15, 9, 0, 0),
+        // Not in the interval
+        ymd_hms(2024, 2, 15, 9, 0, 0),
+        ymd_hms(2024, 2, 29, 9, 0, 0),
+        // Another day
+        ymd_hms(2024, 3, 16, 9, 0, 0),
+        ymd_hms(2024, 3, 30, 9, 0, 0),
+        // Another time
+        ymd_hms(2024, 3, 15, 10, 0, 0),
+    ] {
+        assert_eq!(rrule.matches(dt_start, dt), Ok(false), "{dt}");
+    }
+
+    // The same as generating the recurrences
+    let set = RRuleSet::new(dt_start).rrule(rrule.clone());
+    let dates = set.all(50).dates;
+    let mut dt = dt_start;
+    while dt <= dates[49] {
+        assert_eq!(rrule.matches(dt_start, dt), Ok(dates.contains(&dt)), "{dt}");
+        dt += chrono::Duration::days(1);
+    }
+}
+
+#[test]
+fn matches_the_recurrences() {
+    for (input, step) in [
+        (
+            "DTSTART:20240115T090000Z\nRRULE:FREQ=YEARLY;INTERVAL=2;BYWEEKNO=1,52,53;BYDAY=MO,SU",
+            chrono::Duration::days(1),
+        ),
+        (
+            "DTSTART:20240131T090000Z\nRRULE:RSCALE=GREGORIAN;FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=31;SKIP=FORWARD",
+            chrono::Duration::days(1),
+        ),
+        (
+            "DTSTART:20240101T090000Z\nRRULE:RSCALE=GREGORIAN;FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=-31;SKIP=BACKWARD",
+            chrono::Duration::days(1),
+        ),
+        (
+            "DTSTART:20240103T090000Z\nRRULE:FREQ=WEEKLY;INTERVAL=2;WKST=SU;BYDAY=SU,TU,SA",
+            chrono::Duration::days(1),
+        ),
+        (
+            "DTSTART:20240103T090000Z\nRRULE:FREQ=DAILY;INTERVAL=3;BYHOUR=9,21",
+            chrono::Duration::hours(1),
+        ),
+        (
+            "DTSTART;TZID=Europe/Berlin:20240330T223000\nRRULE:FREQ=HOURLY;INTERVAL=5;BYMINUTE=0,30",
+            chrono::Duration::minutes(30),
+        ),
+        (
+            "DTSTART:20240103T090000Z\nRRULE:FREQ=MINUTELY;INTERVAL=7;BYDAY=TH;BYSECOND=0,30",
+            chrono::Duration::seconds(30),
+        ),
+    ] {
+        let set: RRuleSet = input.parse().unwrap();
+        let dt_start = *set.get_dt_start();
+        let rrule = &set.get_rrule()[0];
+        let dates = set.clone().all(200).dates;
+        let mut dt = dt_start;
+        while dt <= dates[199] {
+            assert_eq!(
+                rrule.matches(dt_start, dt),
+                Ok(dates.contains(&dt)),
+                "{input:?}: {dt}"
+            );
+            dt += step;
+        }
+    }
+}
+
+#[test]
+fn matches_distant_datetime() {
+    let dt_start = ymd_hms(2024, 1, 1, 9, 0, 0);
+    let rrule = RRule::new(Frequency::Minutely)
+        .interval(7)
+        .by_hour(vec![9])
+        .validate(dt_start)
+        .unwrap();
+
+    // Over 200 years later, so there are millions of recurrences before it
+    let dt = ymd_hms(2227, 1, 1, 9, 0, 0);
+    assert_eq!((dt - dt_start).num_minutes() % 7, 0);
+    assert_eq!(rrule.matches(dt_start, dt), Ok(true));
+    assert_eq!(
+        rrule.matches(dt_start, dt + chrono::Duration::minutes(1)),
+        Ok(false)
+    );
+    assert_eq!(
+        rrule.matches(dt_start, dt + chrono::Duration::minutes(7)),
+        Ok(true)
+    );
+}
@@ -1,5 +1,9 @@
 use crate::tests::common::{check_occurrences, test_recurring_rrule_set, ymd_hms};
-use crate::{Frequency, NWeekday, RRule, RRuleSet, Weekday};
+use crate::{
+    Event, Explanation, Frequency, NWeekday, ParseError, RRule, RRuleError, RRuleSet,
+    RRuleSetBuilder, RuleSnapshot, Skip, Tz, ValidationError, Weekday,
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate};
 
 #[test]
 #[cfg(feature = "exrule")]
@@ -658,3 +662,1400 @@ fn yearly_with_interval_2() {
         &[ymd_hms(1960, 1, 1, 9, 0, 0), ymd_hms(1962, 1, 1, 9, 0, 0)],
     );
 }
+
+#[test]
+fn rrules_with_same_dates_are_deduplicated() {
+    let dt_start = ymd_hms(1997, 9, 1, 9, 0, 0);
+
+    // Every day of the week, for 2 weeks
+    let rrule1 = RRule::new(Frequency::Daily)
+        .count(14)
+        .validate(dt_start)
+        .unwrap();
+    // Mondays and Wednesdays, which are also generated by the daily rule
+    let rrule2 = RRule::new(Frequency::Weekly)
+        .count(4)
+        .by_weekday(vec![
+            NWeekday::Every(Weekday::Mon),
+            NWeekday::Every(Weekday::Wed),
+        ])
+        .validate(dt_start)
+        .unwrap();
+
+    let set = RRuleSet::new(dt_start)
+        .rrule(rrule1)
+        .rrule(rrule2)
+        .rdate(ymd_hms(1997, 9, 3, 9, 0, 0))
+        .rdate(ymd_hms(1997, 9, 20, 9, 0, 0));
+
+    let expected = (1..=14)
+        .map(|day| ymd_hms(1997, 9, day, 9, 0, 0))
+        .chain([ymd_hms(1997, 9, 20, 9, 0, 0)])
+        .collect::<Vec<_>>();
+    test_recurring_rrule_set(set, &expected);
+}
+
+#[test]
+fn sample_daily() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=10"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        set.sample(3, 3),
+        vec![
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 8, 9, 0, 0),
+        ]
+    );
+    // Capped by the end of the rule
+    assert_eq!(
+        set.sample(3, 10),
+        vec![
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 8, 9, 0, 0),
+            ymd_hms(1997, 9, 11, 9, 0, 0),
+        ]
+    );
+    assert_eq!(set.sample(0, 2).len(), 2);
+    assert!(set.sample(3, 0).is_empty());
+}
+
+#[test]
+fn is_empty_and_describe() {
+    let set = RRuleSet::new(ymd_hms(1997, 9, 2, 9, 0, 0));
+    assert!(set.is_empty());
+    assert_eq!(set.describe(), "0 RRULE, 0 RDATE, 0 EXRULE, 0 EXDATE");
+
+    let set = set
+        .rdate(ymd_hms(1997, 9, 3, 9, 0, 0))
+        .rdate(ymd_hms(1997, 9, 4, 9, 0, 0))
+        .exdate(ymd_hms(1997, 9, 4, 9, 0, 0));
+    assert!(!set.is_empty());
+    assert_eq!(set.describe(), "0 RRULE, 2 RDATE, 0 EXRULE, 1 EXDATE");
+    // Not the same as having no recurrences
+    assert_eq!(set.all(10).dates.len(), 1);
+}
+
+#[test]
+fn expand_to_events_timed() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=2"
+        .parse()
+        .unwrap();
+
+    // Without a duration, timed events end when they start
+    assert_eq!(
+        set.expand_to_events(10, None),
+        vec![
+            Event {
+                start: ymd_hms(1997, 9, 2, 9, 0, 0),
+                end: ymd_hms(1997, 9, 2, 9, 0, 0),
+            },
+            Event {
+                start: ymd_hms(1997, 9, 3, 9, 0, 0),
+                end: ymd_hms(1997, 9, 3, 9, 0, 0),
+            },
+        ]
+    );
+
+    let set = set.duration(Duration::hours(2));
+    assert_eq!(
+        set.expand_to_events(10, None),
+        vec![
+            Event {
+                start: ymd_hms(1997, 9, 2, 9, 0, 0),
+                end: ymd_hms(1997, 9, 2, 11, 0, 0),
+            },
+            Event {
+                start: ymd_hms(1997, 9, 3, 9, 0, 0),
+                end: ymd_hms(1997, 9, 3, 11, 0, 0),
+            },
+        ]
+    );
+
+    // A supplied duration takes precedence over the one of the set
+    let events = set.expand_to_events(1, Some(Duration::minutes(45)));
+    assert_eq!(
+        events,
+        vec![Event {
+            start: ymd_hms(1997, 9, 2, 9, 0, 0),
+            end: ymd_hms(1997, 9, 2, 9, 45, 0),
+        }]
+    );
+}
+
+#[test]
+fn expand_to_events_all_day() {
+    let set: RRuleSet = "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=WEEKLY;COUNT=2"
+        .parse()
+        .unwrap();
+
+    let events = set.expand_to_events(10, None);
+    assert_eq!(events.len(), 2);
+    for event in events {
+        assert_eq!(event.end - event.start, Duration::days(1));
+    }
+
+    let events = set.expand_to_events(10, Some(Duration::days(3)));
+    for event in events {
+        assert_eq!(event.end - event.start, Duration::days(3));
+    }
+}
+
+#[test]
+fn expand_to_events_all_day_across_dst() {
+    // The clocks go forward on the 31st, so that day is only 23 hours long
+    let set: RRuleSet = "DTSTART;TZID=Europe/Berlin;VALUE=DATE:20240330\nRRULE:FREQ=DAILY;COUNT=2"
+        .parse()
+        .unwrap();
+
+    let events = set.expand_to_events(10, None);
+    assert_eq!(events[1].start.to_rfc3339(), "2024-03-31T00:00:00+01:00");
+    assert_eq!(events[1].end.to_rfc3339(), "2024-04-01T00:00:00+02:00");
+
+    let noon = events[1].start + Duration::hours(12);
+    assert_eq!(set.all_events_between(noon, noon), vec![events[1]]);
+}
+
+#[test]
+fn all_day_string_roundtrip() {
+    for input in [
+        "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=WEEKLY;COUNT=2;BYDAY=TU",
+        "DTSTART;TZID=UTC;VALUE=DATE:19970902\nRRULE:FREQ=WEEKLY;COUNT=2;BYDAY=TU",
+        "DTSTART;TZID=Europe/Berlin;VALUE=DATE:19970902\nRRULE:FREQ=WEEKLY;COUNT=2;BYDAY=TU",
+    ] {
+        let set: RRuleSet = input.parse().unwrap();
+        assert_eq!(set.to_string(), input);
+
+        let parsed: RRuleSet = set.to_string().parse().unwrap();
+        assert!(parsed.is_all_day());
+        assert_eq!(parsed, set);
+    }
+}
+
+#[test]
+fn collect_rrules_into_set() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let rrules = vec![
+        RRule::new(Frequency::Daily)
+            .count(2)
+            .validate(dt_start)
+            .unwrap(),
+        RRule::new(Frequency::Weekly)
+            .count(2)
+            .validate(dt_start)
+            .unwrap(),
+    ];
+
+    let set = rrules.clone().into_iter().collect::<RRuleSet>();
+    assert_eq!(set.get_rrule(), &rrules);
+    assert_eq!(*set.get_dt_start(), ymd_hms(1970, 1, 1, 0, 0, 0));
+}
+
+#[test]
+fn explain() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=5\nRDATE:19970910T090000Z\nEXDATE:19970903T090000Z"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 2, 9, 0, 0)),
+        Explanation::Included
+    );
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 10, 9, 0, 0)),
+        Explanation::Included
+    );
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 3, 9, 0, 0)),
+        Explanation::ExcludedByExDate
+    );
+    // After the last recurrence of the rrule
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 7, 9, 0, 0)),
+        Explanation::NotGenerated
+    );
+    // Not at the right time
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 4, 10, 0, 0)),
+        Explanation::NotGenerated
+    );
+}
+
+#[test]
+#[cfg(feature = "exrule")]
+fn explain_exrule() {
+    let set: RRuleSet =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=5\nEXRULE:FREQ=DAILY;INTERVAL=2"
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 4, 9, 0, 0)),
+        Explanation::ExcludedByExRule
+    );
+    assert_eq!(
+        set.explain(ymd_hms(1997, 9, 5, 9, 0, 0)),
+        Explanation::Included
+    );
+}
+
+#[test]
+fn count_includes_excluded() {
+    let set: RRuleSet =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=5\nEXDATE:19970903T090000Z"
+            .parse()
+            .unwrap();
+
+    // By default, the excluded date counts towards the `COUNT`
+    test_recurring_rrule_set(
+        set.clone(),
+        &[
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 6, 9, 0, 0),
+        ],
+    );
+
+    test_recurring_rrule_set(
+        set.count_includes_excluded(false),
+        &[
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 6, 9, 0, 0),
+            ymd_hms(1997, 9, 7, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn clone_with_rules() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3\nRDATE:19971001T090000Z\nEXDATE:19970903T090000Z"
+        .parse()
+        .unwrap();
+
+    let variant = set
+        .clone_with_rules(vec![RRule::new(Frequency::Weekly).count(2)])
+        .unwrap();
+    assert_eq!(variant.get_dt_start(), set.get_dt_start());
+    assert_eq!(variant.get_rdate(), set.get_rdate());
+    assert_eq!(variant.get_exdate(), set.get_exdate());
+    assert_eq!(
+        variant.get_rrule(),
+        &vec![RRule::new(Frequency::Weekly)
+            .count(2)
+            .validate(*set.get_dt_start())
+            .unwrap()]
+    );
+    test_recurring_rrule_set(
+        variant,
+        &[
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 9, 9, 0, 0),
+            ymd_hms(1997, 10, 1, 9, 0, 0),
+        ],
+    );
+
+    // The rrules are validated
+    assert!(set
+        .clone_with_rules(vec![RRule::new(Frequency::Daily).by_hour(vec![24])])
+        .is_err());
+}
+
+#[test]
+fn merged_rrules_and_rdates_are_increasing() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let set = RRuleSet::new(dt_start)
+        .rrule(
+            RRule::new(Frequency::Daily)
+                .count(5)
+                .validate(dt_start)
+                .unwrap(),
+        )
+        .rrule(
+            RRule::new(Frequency::Hourly)
+                .interval(12)
+                .count(6)
+                .validate(dt_start)
+                .unwrap(),
+        )
+        .set_rdates(vec![
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+            ymd_hms(1997, 9, 1, 9, 0, 0),
+            ymd_hms(1997, 9, 3, 10, 0, 0),
+            ymd_hms(1997, 9, 3, 8, 0, 0),
+        ]);
+
+    // The iterator asserts that dates are increasing in debug builds as well
+    let dates = set.all_iter().collect::<Vec<_>>();
+    assert!(dates.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(dates.len(), 11);
+}
+
+#[test]
+fn builder_reports_every_invalid_rrule() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let res = RRuleSetBuilder::new(dt_start)
+        .rrule(RRule::new(Frequency::Daily).count(2))
+        .rrule(RRule::new(Frequency::Daily).by_hour(vec![24]))
+        .rrule(RRule::new(Frequency::Daily).by_minute(vec![60]))
+        .build();
+
+    let err = res.unwrap_err();
+    assert_eq!(
+        err,
+        RRuleError::Multiple(vec![
+            RRule::new(Frequency::Daily)
+                .by_hour(vec![24])
+                .validate(dt_start)
+                .unwrap_err(),
+            RRule::new(Frequency::Daily)
+                .by_minute(vec![60])
+                .validate(dt_start)
+                .unwrap_err(),
+        ])
+    );
+    assert!(err.to_string().starts_with("2 RRule errors: "));
+}
+
+#[test]
+fn builder() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let set = RRuleSetBuilder::new(dt_start)
+        .rrule(RRule::new(Frequency::Daily).count(3))
+        .rdate(ymd_hms(1997, 9, 10, 9, 0, 0))
+        .exdate(ymd_hms(1997, 9, 3, 9, 0, 0))
+        .build()
+        .unwrap();
+
+    test_recurring_rrule_set(
+        set,
+        &[
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+            ymd_hms(1997, 9, 10, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn merge() {
+    let set: RRuleSet =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3\nEXDATE:19970909T090000Z"
+            .parse()
+            .unwrap();
+    let other: RRuleSet =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=MONTHLY;COUNT=2\nRDATE:19970905T090000Z"
+            .parse()
+            .unwrap();
+
+    let mut expected = set
+        .clone()
+        .all(10)
+        .dates
+        .into_iter()
+        .chain(other.clone().all(10).dates)
+        .collect::<Vec<_>>();
+    expected.sort();
+    expected.dedup();
+
+    let merged = set.merge(other).unwrap();
+    assert_eq!(merged.get_rrule().len(), 2);
+    test_recurring_rrule_set(merged, &expected);
+}
+
+#[test]
+fn merge_with_different_dt_start() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+    let other: RRuleSet = "DTSTART:19970903T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        set.merge(other).unwrap_err(),
+        ValidationError::MergeDtStartMismatch {
+            dt_start: "1997-09-02T09:00:00+00:00".into(),
+            other_dt_start: "1997-09-03T09:00:00+00:00".into(),
+        }
+        .into()
+    );
+}
+
+#[test]
+fn peek() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=2"
+        .parse()
+        .unwrap();
+    let mut iter = set.all_iter();
+
+    assert_eq!(iter.peek(), Some(&ymd_hms(1997, 9, 2, 9, 0, 0)));
+    // Peeking again doesn't advance the iterator
+    assert_eq!(iter.peek(), Some(&ymd_hms(1997, 9, 2, 9, 0, 0)));
+    assert_eq!(iter.next(), Some(ymd_hms(1997, 9, 2, 9, 0, 0)));
+    assert_eq!(iter.next(), Some(ymd_hms(1997, 9, 3, 9, 0, 0)));
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn reset() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3\nEXDATE:19970903T090000Z\nRDATE:19970901T090000Z"
+        .parse()
+        .unwrap();
+    let expected = vec![
+        ymd_hms(1997, 9, 1, 9, 0, 0),
+        ymd_hms(1997, 9, 2, 9, 0, 0),
+        ymd_hms(1997, 9, 4, 9, 0, 0),
+    ];
+    let mut iter = set.all_iter();
+
+    assert_eq!(iter.next(), Some(expected[0]));
+    assert_eq!(iter.next(), Some(expected[1]));
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), expected);
+
+    // Resetting an exhausted iterator restarts it as well
+    iter.reset();
+    assert_eq!(iter.peek(), Some(&expected[0]));
+    assert_eq!(iter.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn span() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=10"
+        .parse()
+        .unwrap();
+    assert_eq!(set.span(), Ok(Some(Duration::days(9))));
+
+    let set = RRuleSet::new(ymd_hms(1997, 9, 2, 9, 0, 0));
+    assert_eq!(set.span(), Ok(None));
+
+    let set = set.rdate(ymd_hms(1997, 9, 2, 9, 0, 0));
+    assert_eq!(set.span(), Ok(Some(Duration::zero())));
+
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    assert_eq!(set.span(), Err(RRuleError::InfiniteRule));
+}
+
+#[test]
+fn multiple_exdate_and_rdate_lines_accumulate() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=6\nEXDATE:19970903T090000Z,19970904T090000Z\nEXDATE:19970906T090000Z\nRDATE:19970910T090000Z\nRDATE:19970911T090000Z,19970912T090000Z"
+        .parse()
+        .unwrap();
+
+    assert_eq!(set.get_exdate().len(), 3);
+    assert_eq!(set.get_rdate().len(), 3);
+    test_recurring_rrule_set(
+        set,
+        &[
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 7, 9, 0, 0),
+            ymd_hms(1997, 9, 10, 9, 0, 0),
+            ymd_hms(1997, 9, 11, 9, 0, 0),
+            ymd_hms(1997, 9, 12, 9, 0, 0),
+        ],
+    );
+}
+
+#[test]
+fn after_pinned_reference_time() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+    let now = ymd_hms(2024, 1, 1, 12, 0, 0);
+
+    let expected = vec![
+        ymd_hms(2024, 1, 2, 9, 0, 0),
+        ymd_hms(2024, 1, 9, 9, 0, 0),
+        ymd_hms(2024, 1, 16, 9, 0, 0),
+    ];
+    assert_eq!(set.clone().after(now).all(3).dates, expected);
+    // The same reference time always yields the same recurrences
+    assert_eq!(set.after(now).all(3).dates, expected);
+}
+
+#[test]
+fn rdate_only() {
+    let set = RRuleSet::new(ymd_hms(1997, 9, 2, 9, 0, 0))
+        .rdate(ymd_hms(1997, 9, 9, 9, 0, 0))
+        .rdate(ymd_hms(1997, 9, 2, 9, 0, 0))
+        .rdate(ymd_hms(1997, 9, 5, 9, 0, 0))
+        .rdate(ymd_hms(1997, 9, 2, 9, 0, 0))
+        .exdate(ymd_hms(1997, 9, 5, 9, 0, 0));
+
+    test_recurring_rrule_set(
+        set,
+        &[ymd_hms(1997, 9, 2, 9, 0, 0), ymd_hms(1997, 9, 9, 9, 0, 0)],
+    );
+
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRDATE:19970909T090000Z,19970902T090000Z,19970905T090000Z\nEXDATE:19970905T090000Z"
+        .parse()
+        .unwrap();
+    let result = set.all(10);
+    assert!(!result.limited);
+    assert_eq!(
+        result.dates,
+        vec![ymd_hms(1997, 9, 2, 9, 0, 0), ymd_hms(1997, 9, 9, 9, 0, 0)]
+    );
+}
+
+#[test]
+fn all_checked() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.clone().all_checked(3),
+        Ok(vec![
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 3, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+        ])
+    );
+    assert_eq!(set.all_checked(2), Err(RRuleError::LimitReached));
+
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    assert_eq!(set.all_checked(100), Err(RRuleError::LimitReached));
+}
+
+#[test]
+#[cfg(feature = "exrule")]
+fn all_checked_validation_limits() {
+    // Only the Sunday recurrences remain, which are too far apart for the iteration limits
+    let set: RRuleSet = "DTSTART:20230102T090000Z\n\
+        RRULE:FREQ=SECONDLY\n\
+        EXRULE:FREQ=SECONDLY;BYDAY=MO,TU,WE,TH,FR,SA"
+        .parse()
+        .unwrap();
+    assert!(set.clone().all(3).limited);
+    assert!(matches!(set.all_checked(3), Err(RRuleError::IterError(_))));
+}
+
+#[test]
+fn all_checked_max_limit() {
+    let set: RRuleSet = "DTSTART:20230101T000000Z\nRRULE:FREQ=MINUTELY"
+        .parse()
+        .unwrap();
+    assert_eq!(set.all_checked(u16::MAX), Err(RRuleError::LimitReached));
+
+    let set: RRuleSet = "DTSTART:20230101T000000Z\nRRULE:FREQ=MINUTELY;COUNT=65535"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all_checked(u16::MAX).map(|dates| dates.len()),
+        Ok(65535)
+    );
+}
+
+#[test]
+fn occurrences_matching() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    let weekdays =
+        set.occurrences_matching(|dt| !matches!(dt.weekday(), Weekday::Sat | Weekday::Sun), 5);
+    assert_eq!(
+        weekdays,
+        vec![
+            ymd_hms(1997, 9, 2, 9, 0, 0),
+            ymd_hms(1997, 9, 3, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 8, 9, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn occurrences_equal() {
+    let rrule: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3"
+        .parse()
+        .unwrap();
+    let rdate: RRuleSet =
+        "DTSTART:19970902T090000Z\nRDATE:19970916T090000Z,19970902T090000Z,19970909T090000Z"
+            .parse()
+            .unwrap();
+    assert!(rrule.occurrences_equal(&rdate, 10));
+    assert!(rdate.occurrences_equal(&rrule, 10));
+
+    // Only the first `limit` recurrences are compared
+    let infinite: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+    assert!(rrule.occurrences_equal(&infinite, 3));
+    assert!(!rrule.occurrences_equal(&infinite, 4));
+}
+
+#[test]
+fn next_n() {
+    let set: RRuleSet =
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=10\nEXDATE:19970906T090000Z"
+            .parse()
+            .unwrap();
+
+    // Strictly after the reference, and skipping the exdate
+    assert_eq!(
+        set.next_n(ymd_hms(1997, 9, 4, 9, 0, 0), 3),
+        Ok(vec![
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 7, 9, 0, 0),
+            ymd_hms(1997, 9, 8, 9, 0, 0),
+        ])
+    );
+    // Fewer recurrences are left than requested
+    assert_eq!(
+        set.next_n(ymd_hms(1997, 9, 10, 0, 0, 0), 3),
+        Ok(vec![
+            ymd_hms(1997, 9, 10, 9, 0, 0),
+            ymd_hms(1997, 9, 11, 9, 0, 0),
+        ])
+    );
+
+    // A distant reference doesn't generate the recurrences before it
+    let set: RRuleSet = "DTSTART:20200101T000000Z\nRRULE:FREQ=MINUTELY"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.next_n(ymd_hms(2030, 6, 1, 0, 0, 30), 2),
+        Ok(vec![
+            ymd_hms(2030, 6, 1, 0, 1, 0),
+            ymd_hms(2030, 6, 1, 0, 2, 0),
+        ])
+    );
+}
+
+#[test]
+fn all_events_between() {
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse::<RRuleSet>()
+        .unwrap()
+        .duration(Duration::days(3));
+
+    // The event of the 9th starts before the window, but still lasts until the 12th
+    let events =
+        set.all_events_between(ymd_hms(1997, 9, 11, 0, 0, 0), ymd_hms(1997, 9, 20, 0, 0, 0));
+    assert_eq!(
+        events,
+        vec![
+            Event {
+                start: ymd_hms(1997, 9, 9, 9, 0, 0),
+                end: ymd_hms(1997, 9, 12, 9, 0, 0),
+            },
+            Event {
+                start: ymd_hms(1997, 9, 16, 9, 0, 0),
+                end: ymd_hms(1997, 9, 19, 9, 0, 0),
+            },
+        ]
+    );
+
+    // Without a duration, only the events starting in the window are returned
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+    let events =
+        set.all_events_between(ymd_hms(1997, 9, 11, 0, 0, 0), ymd_hms(1997, 9, 20, 0, 0, 0));
+    assert_eq!(
+        events,
+        vec![Event {
+            start: ymd_hms(1997, 9, 16, 9, 0, 0),
+            end: ymd_hms(1997, 9, 16, 9, 0, 0),
+        }]
+    );
+}
+
+#[test]
+fn all_naive_local() {
+    let set: RRuleSet = "DTSTART;TZID=America/New_York:19970902T090000\nRRULE:FREQ=DAILY;COUNT=3\nRDATE:19970910T090000Z"
+        .parse()
+        .unwrap();
+
+    let expected = set
+        .clone()
+        .all(10)
+        .dates
+        .into_iter()
+        .map(|dt| {
+            dt.with_timezone(&set.get_dt_start().timezone())
+                .naive_local()
+        })
+        .collect::<Vec<_>>();
+    let naive = set.all_naive_local(10);
+    assert_eq!(naive, expected);
+    assert_eq!(naive[0].to_string(), "1997-09-02 09:00:00");
+    // The rdate is converted to the timezone of `DTSTART`
+    assert_eq!(naive[3].to_string(), "1997-09-10 05:00:00");
+}
+
+#[test]
+fn to_ical_lines() {
+    let rdates = (1..=20)
+        .map(|day| format!("199710{day:02}T090000Z"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let set: RRuleSet =
+        format!("DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3\nRDATE:{rdates}")
+            .parse()
+            .unwrap();
+
+    let lines = set.to_ical_lines();
+    assert!(lines.len() > 3);
+    assert!(lines.iter().all(|line| line.len() <= 75));
+    assert_eq!(
+        lines.join("\r\n").replace("\r\n ", ""),
+        set.to_string().replace('\n', "\r\n")
+    );
+}
+
+#[test]
+fn after_distant_reference_time() {
+    let rules = [
+        "FREQ=DAILY",
+        "FREQ=DAILY;INTERVAL=7",
+        "FREQ=WEEKLY;INTERVAL=3",
+        "FREQ=MONTHLY",
+        "FREQ=MONTHLY;INTERVAL=5",
+        "FREQ=YEARLY;INTERVAL=2",
+        "FREQ=HOURLY;INTERVAL=97",
+        "FREQ=DAILY;UNTIL=20240301T000000Z",
+        // Not skipped, but still correct
+        "FREQ=DAILY;COUNT=20000",
+        "FREQ=MONTHLY;BYDAY=MO",
+    ];
+    let afters = [
+        ymd_hms(1970, 1, 1, 0, 0, 0),
+        ymd_hms(2024, 2, 14, 9, 0, 0),
+        ymd_hms(2024, 2, 14, 9, 0, 1),
+        ymd_hms(2024, 3, 31, 1, 30, 0),
+        ymd_hms(2027, 11, 5, 23, 0, 0),
+    ];
+    for dt_start in [
+        "DTSTART:19700101T090000Z",
+        "DTSTART;TZID=Europe/Berlin:19700328T023000",
+        "DTSTART;TZID=America/New_York:19701014T000000",
+    ] {
+        for rule in rules {
+            let set: RRuleSet = format!("{dt_start}\nRRULE:{rule}").parse().unwrap();
+            for after in afters {
+                let expected = set
+                    .all_iter()
+                    .skip_while(|date| *date < after)
+                    .take(5)
+                    .collect::<Vec<_>>();
+                let result = set.clone().after(after).all(5);
+                assert_eq!(result.dates, expected, "{dt_start} {rule} {after}");
+            }
+        }
+    }
+}
+
+#[test]
+fn sub_daily_narrow_distant_window() {
+    // Without skipping, this would step through hundreds of millions of seconds
+    let set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20200101T000000\n\
+        RRULE:FREQ=SECONDLY;INTERVAL=2"
+        .parse()
+        .unwrap();
+    let dates = set
+        .after(ymd_hms(2030, 6, 1, 12, 0, 0))
+        .before(ymd_hms(2030, 6, 1, 12, 0, 7))
+        .all(100)
+        .dates;
+    assert_eq!(
+        dates,
+        vec![
+            ymd_hms(2030, 6, 1, 12, 0, 0),
+            ymd_hms(2030, 6, 1, 12, 0, 2),
+            ymd_hms(2030, 6, 1, 12, 0, 4),
+            ymd_hms(2030, 6, 1, 12, 0, 6),
+        ]
+    );
+
+    for (rule, expected) in [
+        (
+            "FREQ=MINUTELY;INTERVAL=45",
+            [
+                ymd_hms(2030, 6, 1, 12, 45, 0),
+                ymd_hms(2030, 6, 1, 13, 30, 0),
+            ],
+        ),
+        (
+            "FREQ=HOURLY;INTERVAL=5",
+            [ymd_hms(2030, 6, 1, 13, 0, 0), ymd_hms(2030, 6, 1, 18, 0, 0)],
+        ),
+    ] {
+        let set: RRuleSet = format!("DTSTART:20200101T090000Z\nRRULE:{rule}")
+            .parse()
+            .unwrap();
+        let set = set.after(ymd_hms(2030, 6, 1, 12, 0, 1));
+        assert_eq!(set.all(2).dates, expected, "{rule}");
+    }
+}
+
+#[test]
+#[ignore = "benchmark, run with `cargo test --release -- --ignored --nocapture`"]
+fn bench_after_distant_reference_time() {
+    let set: RRuleSet = "DTSTART:19700101T090000Z\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    let after = ymd_hms(2024, 1, 1, 0, 0, 0);
+
+    let start = std::time::Instant::now();
+    for _ in 0..1000 {
+        assert_eq!(set.clone().after(after).all(1).dates.len(), 1);
+    }
+    println!("after with skipping: {:?}", start.elapsed());
+
+    let start = std::time::Instant::now();
+    for _ in 0..1000 {
+        assert!(set.all_iter().find(|date| *date >= after).is_some());
+    }
+    println!("after without skipping: {:?}", start.elapsed());
+}
+
+#[test]
+fn add_rrule_str() {
+    let dt_start = ymd_hms(1997, 9, 2, 9, 0, 0);
+    let mut set = RRuleSet::new(dt_start);
+    set.add_rrule_str("FREQ=WEEKLY;COUNT=3;BYDAY=TU,TH")
+        .unwrap();
+    set.add_rrule_str("RRULE:FREQ=MONTHLY;COUNT=2").unwrap();
+
+    let concatenated: RRuleSet = "DTSTART:19970902T090000Z\n\
+        RRULE:FREQ=WEEKLY;COUNT=3;BYDAY=TU,TH\n\
+        RRULE:FREQ=MONTHLY;COUNT=2"
+        .parse()
+        .unwrap();
+    assert_eq!(set, concatenated);
+    assert_eq!(set.all(10).dates, concatenated.all(10).dates);
+
+    let mut set = RRuleSet::new(dt_start);
+    assert!(set.add_rrule_str("FREQ=DAILY;BYHOUR=24").is_err());
+    assert!(set.add_rrule_str("FREQ=SOMETIMES").is_err());
+    assert!(set.get_rrule().is_empty());
+}
+
+#[test]
+fn from_str_lenient_skips_blank_and_component_lines() {
+    let input = "BEGIN:VEVENT\n\
+        DTSTART:19970902T090000Z\n\
+        \n\
+        RRULE:FREQ=DAILY;COUNT=3\n\
+        END:VEVENT";
+    assert!(input.parse::<RRuleSet>().is_err());
+
+    let (set, warnings) = RRuleSet::from_str_lenient(input).unwrap();
+    assert_eq!(
+        set,
+        "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3"
+            .parse()
+            .unwrap()
+    );
+    assert_eq!(warnings.len(), 3);
+}
+
+#[test]
+#[cfg(feature = "exrule")]
+fn from_str_lenient_warns_about_exrule() {
+    let input = "DTSTART:19970902T090000Z\n\
+        RRULE:FREQ=DAILY;COUNT=4\n\
+        EXRULE:FREQ=DAILY;INTERVAL=2;COUNT=2";
+
+    let (set, warnings) = RRuleSet::from_str_lenient(input).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("EXRULE"));
+    assert_eq!(
+        set.all(10).dates,
+        vec![ymd_hms(1997, 9, 3, 9, 0, 0), ymd_hms(1997, 9, 5, 9, 0, 0)]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "exrule"))]
+fn from_str_lenient_warns_about_ignored_exrule() {
+    let input = "DTSTART:19970902T090000Z\n\
+        RRULE:FREQ=DAILY;COUNT=4\n\
+        EXRULE:FREQ=DAILY;INTERVAL=2;COUNT=2";
+
+    let (set, warnings) = RRuleSet::from_str_lenient(input).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("ignored"));
+    assert_eq!(set.all(10).dates.len(), 4);
+}
+
+#[test]
+fn occurrences_stream() {
+    fn stream(input: &str) -> impl Iterator<Item = Result<DateTime<Tz>, RRuleError>> {
+        input.parse::<RRuleSet>().unwrap().occurrences_stream()
+    }
+
+    let dates = stream("DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=3")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        dates,
+        vec![
+            ymd_hms(2024, 1, 1, 9, 0, 0),
+            ymd_hms(2024, 1, 2, 9, 0, 0),
+            ymd_hms(2024, 1, 3, 9, 0, 0),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "exrule")]
+fn occurrences_stream_validation_limits() {
+    // Only the Sunday recurrences remain, which are too far apart for the iteration limits
+    let set: RRuleSet = "DTSTART:20230102T090000Z\n\
+        RRULE:FREQ=SECONDLY\n\
+        EXRULE:FREQ=SECONDLY;BYDAY=MO,TU,WE,TH,FR,SA"
+        .parse()
+        .unwrap();
+
+    let mut iter = set.clone().occurrences_stream();
+    assert!(matches!(iter.next(), Some(Err(RRuleError::IterError(_)))));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = set.into_boxed_iter();
+    assert!(matches!(iter.next(), Some(Err(RRuleError::IterError(_)))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn into_boxed_iter() {
+    let daily: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;INTERVAL=2;COUNT=3"
+        .parse()
+        .unwrap();
+    let weekly: RRuleSet = "DTSTART:20240102T120000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+
+    let mut iters = vec![daily.into_boxed_iter(), weekly.into_boxed_iter()]
+        .into_iter()
+        .map(Iterator::peekable)
+        .collect::<Vec<_>>();
+    // Interleave the sets, always taking the earliest next date
+    let mut dates = vec![];
+    while dates.len() < 5 {
+        let next = iters
+            .iter_mut()
+            .filter_map(|iter| Some((*iter.peek()?.as_ref().unwrap(), iter)))
+            .min_by_key(|(date, _)| *date);
+        let Some((date, iter)) = next else {
+            break;
+        };
+        iter.next();
+        dates.push(date);
+    }
+    assert_eq!(
+        dates,
+        vec![
+            ymd_hms(2024, 1, 1, 9, 0, 0),
+            ymd_hms(2024, 1, 2, 12, 0, 0),
+            ymd_hms(2024, 1, 3, 9, 0, 0),
+            ymd_hms(2024, 1, 5, 9, 0, 0),
+            ymd_hms(2024, 1, 9, 12, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn until_before_start() {
+    let input = "DTSTART:20240101T090000Z\n\
+        RRULE:FREQ=DAILY;UNTIL=20231231T090000Z\n\
+        RDATE:20240105T090000Z";
+    assert!(matches!(
+        input.parse::<RRuleSet>(),
+        Err(RRuleError::ValidationError(
+            ValidationError::UntilBeforeStart { .. }
+        ))
+    ));
+
+    let (set, warnings) = RRuleSet::from_str_lenient(input).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(set.get_rrule().is_empty());
+    assert_eq!(set.all(10).dates, vec![ymd_hms(2024, 1, 5, 9, 0, 0)]);
+}
+
+#[test]
+fn unsorted_rdates() {
+    let set: RRuleSet = "DTSTART:19970901T090000Z\n\
+        RDATE:19970905T090000Z,19970901T090000Z,19970903T090000Z"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![
+            ymd_hms(1997, 9, 1, 9, 0, 0),
+            ymd_hms(1997, 9, 3, 9, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+        ]
+    );
+
+    let set: RRuleSet = "DTSTART:19970901T090000Z\n\
+        RRULE:FREQ=DAILY;INTERVAL=2;COUNT=3\n\
+        RDATE:19970906T120000Z,19970902T120000Z\n\
+        RDATE:19970904T120000Z"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.all(10).dates,
+        vec![
+            ymd_hms(1997, 9, 1, 9, 0, 0),
+            ymd_hms(1997, 9, 2, 12, 0, 0),
+            ymd_hms(1997, 9, 3, 9, 0, 0),
+            ymd_hms(1997, 9, 4, 12, 0, 0),
+            ymd_hms(1997, 9, 5, 9, 0, 0),
+            ymd_hms(1997, 9, 6, 12, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn weekday_histogram() {
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=14"
+        .parse()
+        .unwrap();
+    let histogram = set.weekday_histogram(100).unwrap();
+    assert_eq!(histogram.len(), 7);
+    assert!(histogram.values().all(|count| *count == 2));
+
+    assert_eq!(set.weekday_histogram(10), Err(RRuleError::LimitReached));
+
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY"
+        .parse()
+        .unwrap();
+    assert_eq!(set.weekday_histogram(100), Err(RRuleError::InfiniteRule));
+}
+
+#[test]
+fn shift() {
+    let set: RRuleSet = "DTSTART:20240101T090000Z\n\
+        RRULE:FREQ=DAILY;COUNT=4\n\
+        RDATE:20240110T120000Z\n\
+        EXDATE:20240103T090000Z"
+        .parse()
+        .unwrap();
+
+    let shifted = set.shift(Duration::minutes(30)).unwrap();
+    assert_eq!(*shifted.get_dt_start(), ymd_hms(2024, 1, 1, 9, 30, 0));
+    assert_eq!(
+        shifted.all(10).dates,
+        vec![
+            ymd_hms(2024, 1, 1, 9, 30, 0),
+            ymd_hms(2024, 1, 2, 9, 30, 0),
+            ymd_hms(2024, 1, 4, 9, 30, 0),
+            ymd_hms(2024, 1, 10, 12, 30, 0),
+        ]
+    );
+    // The set itself is unchanged
+    assert_eq!(set.all(10).dates[0], ymd_hms(2024, 1, 1, 9, 0, 0));
+
+    // Rule parts derived from `DTSTART` move along, others are kept
+    let set: RRuleSet = "DTSTART:20240101T090000Z\n\
+        RRULE:FREQ=WEEKLY;COUNT=2\n\
+        RRULE:FREQ=DAILY;BYHOUR=18;COUNT=1"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.shift(Duration::days(1)).unwrap().all(10).dates,
+        vec![
+            ymd_hms(2024, 1, 2, 9, 0, 0),
+            ymd_hms(2024, 1, 2, 18, 0, 0),
+            ymd_hms(2024, 1, 9, 9, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn snapshot() {
+    let set: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T090000\n\
+        RRULE:FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=1,-1;UNTIL=20241231T000000Z\n\
+        RDATE;TZID=Europe/Berlin:20240615T120000\n\
+        EXDATE;TZID=Europe/Berlin:20240301T090000"
+        .parse()
+        .unwrap();
+
+    let snapshot = set.snapshot();
+    assert_eq!(snapshot.dt_start, *set.get_dt_start());
+    assert_eq!(snapshot.rdates, *set.get_rdate());
+    assert_eq!(snapshot.exdates, *set.get_exdate());
+    assert!(snapshot.exrules.is_empty());
+    assert_eq!(
+        snapshot.rrules,
+        vec![RuleSnapshot {
+            freq: Frequency::Monthly,
+            interval: 2,
+            count: None,
+            until: Some(ymd_hms(2024, 12, 31, 0, 0, 0)),
+            week_start: Weekday::Mon,
+            by_set_pos: vec![],
+            by_month: vec![],
+            by_month_day: vec![-1, 1],
+            by_year_day: vec![],
+            by_week_no: vec![],
+            by_weekday: vec![],
+            by_hour: vec![9],
+            by_minute: vec![0],
+            by_second: vec![0],
+            by_easter: None,
+            skip: Skip::Omit,
+        }]
+    );
+
+    // Sets with the same recurrences, but different rules, have different snapshots
+    let other: RRuleSet = "DTSTART;TZID=Europe/Berlin:20240101T090000\n\
+        RRULE:FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=1,-1;UNTIL=20241231T000000Z"
+        .parse()
+        .unwrap();
+    assert_ne!(other.snapshot(), snapshot);
+    assert_eq!(other.snapshot().rrules, snapshot.rrules);
+}
+
+#[test]
+fn with_exdates() {
+    let dt_start = ymd_hms(2024, 1, 1, 9, 0, 0);
+    let set: RRuleSet = "DTSTART:20240101T090000Z\n\
+        RRULE:FREQ=DAILY;UNTIL=20240718T090000Z\n\
+        EXDATE:20240101T090000Z"
+        .parse()
+        .unwrap();
+    assert_eq!(set.clone().all(1000).dates.len(), 199);
+
+    // Every other day, including the exdate which is already in the set
+    let exdates = (0..100).map(|day| dt_start + Duration::days(2 * day));
+    let set = set.with_exdates(exdates);
+    assert_eq!(set.get_exdate().len(), 100);
+
+    let dates = set.all(1000).dates;
+    assert_eq!(dates.len(), 100);
+    assert!(dates
+        .iter()
+        .all(|date| (*date - dt_start).num_days() % 2 == 1));
+}
+
+#[test]
+fn nth_from() {
+    let set: RRuleSet = "DTSTART:20240101T090000Z\n\
+        RRULE:FREQ=WEEKLY;BYDAY=TU,TH\n\
+        EXDATE:20240130T090000Z"
+        .parse()
+        .unwrap();
+
+    for reference in [
+        ymd_hms(2023, 12, 1, 0, 0, 0),
+        ymd_hms(2024, 1, 4, 9, 0, 0),
+        ymd_hms(2024, 1, 15, 0, 0, 0),
+        ymd_hms(2030, 6, 1, 0, 0, 0),
+    ] {
+        for n in [0, 4, 10] {
+            let expected = set.all_iter().filter(|dt| *dt > reference).nth(n);
+            assert_eq!(set.nth_from(reference, n), Ok(expected), "{reference} {n}");
+        }
+    }
+
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=WEEKLY;COUNT=3"
+        .parse()
+        .unwrap();
+    assert_eq!(set.nth_from(ymd_hms(2024, 1, 1, 12, 0, 0), 2), Ok(None));
+}
+
+#[test]
+fn first_after_excluding() {
+    let set: RRuleSet = "DTSTART:20240101T090000Z\n\
+        RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\n\
+        EXDATE:20240105T090000Z"
+        .parse()
+        .unwrap();
+    let reference = ymd_hms(2024, 1, 1, 12, 0, 0);
+
+    assert_eq!(
+        set.first_after_excluding(reference, &[]),
+        Ok(Some(ymd_hms(2024, 1, 3, 9, 0, 0)))
+    );
+    // The set's own EXDATE on Friday is still applied
+    assert_eq!(
+        set.first_after_excluding(reference, &[ymd_hms(2024, 1, 3, 9, 0, 0)]),
+        Ok(Some(ymd_hms(2024, 1, 8, 9, 0, 0)))
+    );
+    assert_eq!(set.get_exdate().len(), 1);
+
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=DAILY;COUNT=2"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.first_after_excluding(reference, &[ymd_hms(2024, 1, 2, 9, 0, 0)]),
+        Ok(None)
+    );
+
+    // A distant reference doesn't generate the recurrences before it
+    let set: RRuleSet = "DTSTART:20200101T000000Z\nRRULE:FREQ=MINUTELY"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        set.first_after_excluding(
+            ymd_hms(2030, 6, 1, 0, 0, 30),
+            &[ymd_hms(2030, 6, 1, 0, 1, 0), ymd_hms(2030, 6, 1, 0, 2, 0)]
+        ),
+        Ok(Some(ymd_hms(2030, 6, 1, 0, 3, 0)))
+    );
+}
+
+#[test]
+fn zero_count() {
+    let input = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=0";
+    assert_eq!(
+        input.parse::<RRuleSet>(),
+        Err(RRuleError::ParserError(ParseError::InvalidCount(
+            "0".into()
+        )))
+    );
+
+    let (set, warnings) = RRuleSet::from_str_lenient(input).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(set.all(10).dates.is_empty());
+}
+
+#[test]
+fn all_dates() {
+    let set: RRuleSet = "DTSTART;VALUE=DATE:20240105\n\
+        RRULE:FREQ=WEEKLY;BYDAY=FR,SA;COUNT=4"
+        .parse()
+        .unwrap();
+    let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+    assert_eq!(
+        set.all_dates(10),
+        Ok(vec![date(1, 5), date(1, 6), date(1, 12), date(1, 13)])
+    );
+    assert_eq!(set.all_dates(3), Err(RRuleError::LimitReached));
+
+    // The dates are in the timezone of `DTSTART`
+    let set: RRuleSet = "DTSTART;TZID=Asia/Tokyo:20240105T080000\nRRULE:FREQ=DAILY;COUNT=2"
+        .parse()
+        .unwrap();
+    assert_eq!(set.all_dates(10), Ok(vec![date(1, 5), date(1, 6)]));
+}
+
+#[test]
+fn all_in_timezone() {
+    let set: RRuleSet = "DTSTART;TZID=Europe/London:20240325T090000\n\
+        RRULE:FREQ=WEEKLY;COUNT=2"
+        .parse()
+        .unwrap();
+
+    let dates = set.all_in_timezone(Tz::Asia__Tokyo, 10);
+    assert_eq!(
+        dates
+            .iter()
+            .map(|dt| dt.naive_local().to_string())
+            .collect::<Vec<_>>(),
+        // London switches to BST on the 31st of March, so the second meeting is an hour
+        // earlier in Tokyo
+        vec!["2024-03-25 18:00:00", "2024-04-01 17:00:00"]
+    );
+    assert!(dates.iter().all(|dt| dt.timezone() == Tz::Asia__Tokyo));
+    // The instants are unchanged
+    assert_eq!(dates, set.all(10).dates);
+}
+
+#[test]
+fn all_collect_into() {
+    let mut buf = vec![ymd_hms(2000, 1, 1, 0, 0, 0)];
+
+    let set: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=DAILY;COUNT=3"
+        .parse()
+        .unwrap();
+    assert!(!set.all_collect_into(&mut buf, 10));
+    assert_eq!(buf, set.clone().all(10).dates);
+
+    let set: RRuleSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=WEEKLY"
+        .parse()
+        .unwrap();
+    let set = set.after(ymd_hms(2024, 1, 10, 0, 0, 0));
+    assert!(set.all_collect_into(&mut buf, 2));
+    assert_eq!(
+        buf,
+        vec![ymd_hms(2024, 1, 15, 9, 0, 0), ymd_hms(2024, 1, 22, 9, 0, 0)]
+    );
+}
+
+#[test]
+fn case_insensitive_parsing() {
+    let expected: RRuleSet = "DTSTART:19970902T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3"
+        .parse()
+        .unwrap();
+
+    // Rule part names and values are case-insensitive
+    for rrule in [
+        "RRULE:freq=weekly;byday=mo,we;count=3",
+        "RRULE:Freq=Weekly;ByDay=Mo,We;Count=3",
+    ] {
+        let input = format!("DTSTART:19970902T090000Z\n{rrule}");
+        assert_eq!(input.parse::<RRuleSet>().unwrap(), expected);
+        assert_eq!(RRuleSet::from_str_lenient(&input).unwrap().0, expected);
+    }
+
+    // Other lowercase input is only accepted in lenient mode
+    for input in [
+        "dtstart:19970902T090000Z\nrrule:freq=weekly;byday=mo,we;count=3",
+        "DTSTART:19970902t090000z\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3",
+        "DTSTART;value=date-time:19970902T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3",
+    ] {
+        assert!(input.parse::<RRuleSet>().is_err());
+        assert_eq!(RRuleSet::from_str_lenient(input).unwrap().0, expected);
+    }
+}
+
+#[test]
+fn time_on_date_only_event() {
+    for input in [
+        "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=DAILY;BYHOUR=9",
+        "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=WEEKLY;BYMINUTE=30",
+        "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=DAILY\nEXRULE:FREQ=DAILY;BYSECOND=0",
+    ] {
+        let err = input.parse::<RRuleSet>().unwrap_err();
+        assert_eq!(err, RRuleError::TimeOnDateOnlyEvent, "{input}");
+        assert_eq!(err.code(), "time_on_date_only_event");
+    }
+
+    let set = RRuleSet::new(ymd_hms(1997, 9, 2, 0, 0, 0));
+    assert_eq!(
+        set.set_from_string("DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=DAILY;BYHOUR=9"),
+        Err(RRuleError::TimeOnDateOnlyEvent)
+    );
+    assert!("DTSTART:19970902T000000Z\nRRULE:FREQ=DAILY;BYHOUR=9"
+        .parse::<RRuleSet>()
+        .is_ok());
+}
+
+#[test]
+fn is_all_day() {
+    for (input, all_day) in [
+        (
+            "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=DAILY;COUNT=3",
+            true,
+        ),
+        (
+            "DTSTART;VALUE=DATE:19970902\nRDATE;VALUE=DATE:19970910",
+            true,
+        ),
+        ("DTSTART:19970902T000000Z\nRRULE:FREQ=DAILY;COUNT=3", false),
+        (
+            "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=HOURLY;COUNT=3",
+            false,
+        ),
+    ] {
+        let set: RRuleSet = input.parse().unwrap();
+        assert_eq!(set.is_all_day(), all_day, "{input}");
+    }
+}
@@ -5,8 +5,10 @@ use std::str::FromStr;
 fn rrule_set_to_and_from_str() {
     let test_cases = [
         "DTSTART:20120201T093000Z\nRRULE:FREQ=YEARLY;COUNT=3",
-        "DTSTART:20120201T093000Z\nRRULE:FREQ=WEEKLY;INTERVAL=5;BYDAY=-2MO,FR",
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=WEEKLY;INTERVAL=5;BYDAY=MO,FR",
         "DTSTART;TZID=America/New_York:19000201T093000Z\nRRULE:UNTIL=19990404T110000Z;FREQ=WEEKLY;BYDAY=TU,WE",
+        "DTSTART;VALUE=DATE:20120201\nRRULE:FREQ=WEEKLY;COUNT=3",
+        "DTSTART;TZID=Europe/Berlin;VALUE=DATE:20120201\nRRULE:FREQ=DAILY;COUNT=3",
     ];
 
     for test_str in test_cases {
@@ -29,8 +31,10 @@ fn serialize_deserialize_json_to_and_from_rrule_set() {
 
     let test_cases = [
         "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5",
-        "DTSTART:20120201T093000Z\nRRULE:FREQ=WEEKLY;INTERVAL=5;BYDAY=-2MO,FR",
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=WEEKLY;INTERVAL=5;BYDAY=MO,FR",
         "DTSTART;TZID=America/New_York:19000201T093000Z\nRRULE:UNTIL=19990404T110000Z;FREQ=WEEKLY;BYDAY=TU,WE",
+        "DTSTART;VALUE=DATE:20120201\nRRULE:FREQ=WEEKLY;COUNT=3",
+        "DTSTART;TZID=Europe/Berlin;VALUE=DATE:20120201\nRRULE:FREQ=DAILY;COUNT=3",
     ];
 
     for test_str in test_cases {
@@ -43,3 +47,28 @@ fn serialize_deserialize_json_to_and_from_rrule_set() {
         assert_eq!(src_obj, final_obj);
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_deserialize_json_to_and_from_snapshot() {
+    use crate::{RecurrenceSnapshot, RuleSnapshot};
+
+    let test_cases = [
+        "DTSTART:20120201T093000Z\nRRULE:FREQ=DAILY;COUNT=5\nEXDATE:20120203T093000Z",
+        "DTSTART;TZID=America/New_York:20120201T093000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=1,-1\nRDATE:20120215T093000Z",
+    ];
+
+    for test_str in test_cases {
+        let snapshot = RRuleSet::from_str(test_str).unwrap().snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RecurrenceSnapshot>(&json).unwrap(),
+            snapshot
+        );
+
+        let rule = &snapshot.rrules[0];
+        let json = serde_json::to_string(rule).unwrap();
+        assert_eq!(&serde_json::from_str::<RuleSnapshot>(&json).unwrap(), rule);
+    }
+}
@@ -32,6 +32,11 @@ pub enum ValidationError {
     },
     #[error("`{by_rule}` can not be used with the current frequency ({freq}).")]
     InvalidByRuleAndFrequency { by_rule: String, freq: Frequency },
+    #[error(
+        "`BYDAY` has ordinal `{value}`, but ordinals can only be used with a MONTHLY or YEARLY \
+            frequency, not with the current frequency ({freq})."
+    )]
+    InvalidByDayForFreq { value: String, freq: Frequency },
     #[error("`UNTIL` is `{until}`, but `DTSTART` (`{dt_start}`) is later. That should not be happening.")]
     UntilBeforeStart { until: String, dt_start: String },
     #[error(
@@ -59,4 +64,42 @@ pub enum ValidationError {
         until_tz: String,
         expected: Vec<String>,
     },
+    #[error("`{field}` has {len} values, but can't have more than {max} values.")]
+    TooManyValues {
+        field: String,
+        len: usize,
+        max: usize,
+    },
+    #[error("Can't merge sets with a different `DTSTART`, `{dt_start}` and `{other_dt_start}`.")]
+    MergeDtStartMismatch {
+        dt_start: String,
+        other_dt_start: String,
+    },
+}
+
+impl ValidationError {
+    /// Returns a stable, machine-readable code for the error.
+    ///
+    /// Unlike the error message, the code will not change between releases,
+    /// so it can be used to show localized messages.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BySetPosWithoutByRule => "by_set_pos_without_by_rule",
+            Self::InvalidFieldValue { .. } => "invalid_field_value",
+            Self::InvalidFieldValueRange { .. } => "invalid_field_value_range",
+            Self::InvalidFieldValueRangeWithFreq { .. } => "invalid_field_value_range_with_freq",
+            Self::InvalidByRuleAndFrequency { .. } => "invalid_by_rule_and_frequency",
+            Self::InvalidByDayForFreq { .. } => "invalid_by_day_for_freq",
+            Self::UntilBeforeStart { .. } => "until_before_start",
+            Self::TooBigInterval(_) => "too_big_interval",
+            Self::StartYearOutOfRange(_) => "start_year_out_of_range",
+            Self::UnableToGenerateTimeset => "unable_to_generate_timeset",
+            #[cfg(feature = "by-easter")]
+            Self::InvalidByRuleWithByEaster => "invalid_by_rule_with_by_easter",
+            Self::DtStartUntilMismatchTimezone { .. } => "dt_start_until_mismatch_timezone",
+            Self::TooManyValues { .. } => "too_many_values",
+            Self::MergeDtStartMismatch { .. } => "merge_dt_start_mismatch",
+        }
+    }
 }
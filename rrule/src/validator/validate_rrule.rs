@@ -29,6 +29,35 @@ const VALIDATION_PIPELINE: [Validator; 11] = [
     &validate_by_easter,
 ];
 
+/// Checks that none of the BYxxx lists has more values than the number of distinct values
+/// it can have, e.g. 62 for `BYMONTHDAY` (`-31..=-1` and `1..=31`).
+///
+/// This has to run before the lists are deduplicated, and protects against huge inputs.
+pub(crate) fn validate_by_list_sizes(rrule: &RRule<Unvalidated>) -> Result<(), ValidationError> {
+    let sizes = [
+        ("BYSETPOS", rrule.by_set_pos.len(), 732),
+        ("BYMONTH", rrule.by_month.len(), 12),
+        ("BYMONTHDAY", rrule.by_month_day.len(), 62),
+        ("BYYEARDAY", rrule.by_year_day.len(), 732),
+        ("BYWEEKNO", rrule.by_week_no.len(), 106),
+        // Every weekday, and every weekday with an ordinal in `-53..=-1` and `1..=53`
+        ("BYDAY", rrule.by_weekday.len(), 7 + 7 * 106),
+        ("BYHOUR", rrule.by_hour.len(), 24),
+        ("BYMINUTE", rrule.by_minute.len(), 60),
+        ("BYSECOND", rrule.by_second.len(), 60),
+    ];
+    for (field, len, max) in sizes {
+        if len > max {
+            return Err(ValidationError::TooManyValues {
+                field: field.into(),
+                len,
+                max,
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Check if rules are valid as defined by the RFC and crate limitations.
 /// It checks all values in the [`RRule`] and makes sure that they are in
 /// the accepted ranges. If the function returns `Ok`, no errors where found.
@@ -205,6 +234,8 @@ fn validate_by_week_number(
 }
 
 // By_weekday:
+// - MUST NOT be specified with a numeric value when the FREQ rule part is not set
+//   to MONTHLY or YEARLY.
 // - Check if value for `Nth` is within range.
 //   The Range depends on frequency and can only happen weekly, so `/7` from normal count.
 fn validate_by_weekday(
@@ -212,11 +243,19 @@ fn validate_by_weekday(
     _dt_start: &DateTime,
 ) -> Result<(), ValidationError> {
     let range = match rrule.freq {
-        Frequency::Yearly | Frequency::Daily => (-366 / 7)..=(366 / 7 + 1), // TODO is the daily range correct?
+        Frequency::Yearly => (-366 / 7)..=(366 / 7 + 1),
         Frequency::Monthly => (-31 / 7)..=(31 / 7 + 1),
-        Frequency::Weekly => (-53 / 7)..=(53 / 7 + 1),
-        Frequency::Hourly => (-24 / 7)..=(24 / 7 + 1),
-        Frequency::Minutely | Frequency::Secondly => (-60 / 7)..=(60 / 7 + 1),
+        _ => {
+            for item in &rrule.by_weekday {
+                if let NWeekday::Nth(number, _weekday) = item {
+                    return Err(ValidationError::InvalidByDayForFreq {
+                        value: number.to_string(),
+                        freq: rrule.freq,
+                    });
+                }
+            }
+            return Ok(());
+        }
     };
     for item in &rrule.by_weekday {
         if let NWeekday::Nth(number, _weekday) = item {
@@ -366,6 +405,44 @@ mod tests {
 
     const UTC: Tz = Tz::UTC;
 
+    #[test]
+    fn rejects_too_many_values() {
+        let rrule = RRule {
+            freq: Frequency::Monthly,
+            by_month_day: (1..=31).chain(-31..=-1).chain([1]).collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_by_list_sizes(&rrule),
+            Err(ValidationError::TooManyValues {
+                field: "BYMONTHDAY".into(),
+                len: 63,
+                max: 62,
+            })
+        );
+
+        let rrule = RRule {
+            freq: Frequency::Yearly,
+            by_year_day: vec![1; 1000],
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_by_list_sizes(&rrule),
+            Err(ValidationError::TooManyValues {
+                field: "BYYEARDAY".into(),
+                len: 1000,
+                max: 732,
+            })
+        );
+
+        let rrule = RRule {
+            freq: Frequency::Yearly,
+            by_year_day: (1..=366).chain(-366..=-1).collect(),
+            ..Default::default()
+        };
+        assert_eq!(validate_by_list_sizes(&rrule), Ok(()));
+    }
+
     #[test]
     fn rejects_by_set_pos_without_byxxx_rule() {
         let rrule = RRule {
@@ -569,6 +646,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rejects_by_weekday_ordinal_with_weekly_or_finer_freq() {
+        let dt_start = UTC.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap();
+        for freq in ["WEEKLY", "DAILY", "HOURLY", "MINUTELY", "SECONDLY"] {
+            let rrule: RRule<Unvalidated> = format!("FREQ={freq};BYDAY=3MO").parse().unwrap();
+            let res = validate_rrule_forced(&rrule, &dt_start);
+            assert_eq!(
+                res,
+                Err(ValidationError::InvalidByDayForFreq {
+                    value: "3".into(),
+                    freq: rrule.freq,
+                })
+            );
+        }
+
+        // Non-ordinal weekdays are still allowed
+        let rrule: RRule<Unvalidated> = "FREQ=WEEKLY;BYDAY=MO".parse().unwrap();
+        assert!(rrule.validate(dt_start).is_ok());
+    }
+
+    #[test]
+    fn allows_by_weekday_ordinal_with_monthly_or_yearly_freq() {
+        let dt_start = UTC.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap();
+        for freq in ["MONTHLY", "YEARLY"] {
+            let rrule: RRule<Unvalidated> = format!("FREQ={freq};BYDAY=3MO").parse().unwrap();
+            assert!(rrule.build(dt_start).is_ok());
+        }
+    }
+
     #[test]
     fn rejects_start_date_after_until() {
         let rrule = RRule {